@@ -0,0 +1,18 @@
+//! helpers shared by the benchmarks.
+
+use std::time::{Duration, Instant};
+
+/// times `f`, which does `count` operations per call, and prints the best of a few runs.
+pub fn time(name: &str, count: u32, mut f: impl FnMut()) {
+	// warm up, then take the best of a few runs
+	f();
+	let best = (0..5)
+		.map(|_| {
+			let start = Instant::now();
+			f();
+			start.elapsed()
+		})
+		.min()
+		.unwrap_or(Duration::ZERO);
+	println!("{name:<24} {:>10.3?} ({:.3?} each)", best, best / count.max(1));
+}
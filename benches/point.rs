@@ -0,0 +1,71 @@
+//! compares `Point<f32, 3>` arithmetic against hand-written `[f32; 3]` math.
+//!
+//! run with `cargo bench --bench point`.
+
+mod common;
+
+use std::hint::black_box;
+
+use lykoi_data::point::Point;
+
+const PARTICLES: usize = 100_000;
+const STEPS: usize = 10;
+
+fn main() {
+	let dt = black_box(1.0 / 60.0f32);
+	let gravity = black_box([0.0, -9.8, 0.0f32]);
+
+	let init = (0..PARTICLES)
+		.map(|i| {
+			let i = i as f32;
+			([i, i * 0.5, -i], [1.0, i.sin(), i.cos()])
+		})
+		.collect::<Vec<_>>();
+
+	// a simple euler integration step, and an energy sum
+	common::time("array", (PARTICLES * STEPS) as u32, || {
+		let mut particles = init.clone();
+		let mut energy = 0.0;
+		for _ in 0..STEPS {
+			for (pos, vel) in particles.iter_mut() {
+				vel[0] += gravity[0] * dt;
+				vel[1] += gravity[1] * dt;
+				vel[2] += gravity[2] * dt;
+				pos[0] += vel[0] * dt;
+				pos[1] += vel[1] * dt;
+				pos[2] += vel[2] * dt;
+				energy += vel[0] * vel[0] + vel[1] * vel[1] + vel[2] * vel[2];
+			}
+		}
+		black_box((particles, energy));
+	});
+
+	let gravity = Point::new(gravity);
+	let init = init.into_iter().map(|(p, v)| (Point::new(p), Point::new(v))).collect::<Vec<_>>();
+
+	common::time("point", (PARTICLES * STEPS) as u32, || {
+		let mut particles = init.clone();
+		let mut energy = 0.0;
+		for _ in 0..STEPS {
+			for (pos, vel) in particles.iter_mut() {
+				*vel += gravity * dt;
+				*pos += *vel * dt;
+				energy += vel.dot(*vel);
+			}
+		}
+		black_box((particles, energy));
+	});
+
+	common::time("point (const fn)", (PARTICLES * STEPS) as u32, || {
+		let mut particles = init.clone();
+		let mut energy = 0.0;
+		for _ in 0..STEPS {
+			for (pos, vel) in particles.iter_mut() {
+				*vel = vel.const_add(gravity.const_scale(dt));
+				*pos = pos.const_add(vel.const_scale(dt));
+				energy += vel.const_dot(*vel);
+			}
+		}
+		black_box((particles, energy));
+	});
+}
@@ -0,0 +1,77 @@
+//! compares the spatial indexes against a brute force search.
+//!
+//! run with `cargo bench --bench spatial`.
+
+mod common;
+
+use std::hint::black_box;
+
+use lykoi_data::{
+	geom::Aabb,
+	point,
+	point::Point,
+	rng::XorShift64,
+	spatial::{HashGrid, KdTree, Quadtree, Spatial},
+};
+
+const POINTS: usize = 10_000;
+const QUERIES: usize = 1_000;
+const RADIUS: f64 = 2.0;
+const K: usize = 8;
+
+fn bench(name: &str, index: &impl Spatial<f64, usize, 2>, queries: &[Point<f64, 2>]) {
+	common::time(&format!("{name} radius"), QUERIES as u32, || {
+		for &q in queries {
+			black_box(index.radius(q, RADIUS));
+		}
+	});
+	common::time(&format!("{name} nearest"), QUERIES as u32, || {
+		for &q in queries {
+			black_box(index.nearest(q, K));
+		}
+	});
+	common::time(&format!("{name} range"), QUERIES as u32, || {
+		for &q in queries {
+			black_box(index.range(&Aabb::new(q, q + point![RADIUS, RADIUS])));
+		}
+	});
+}
+
+fn main() {
+	let mut rng = XorShift64::new(1);
+	let mut random = || point![rng.range(0.0, 100.0), rng.range(0.0, 100.0)];
+
+	let points = (0..POINTS).map(|_| random()).collect::<Vec<_>>();
+	let queries = (0..QUERIES).map(|_| random()).collect::<Vec<_>>();
+
+	common::time("brute radius", QUERIES as u32, || {
+		for &q in &queries {
+			black_box(points.iter().filter(|p| p.distance_sq(q) <= RADIUS * RADIUS).count());
+		}
+	});
+	common::time("brute nearest", QUERIES as u32, || {
+		for &q in &queries {
+			let mut sorted = points.clone();
+			sorted.select_nth_unstable_by(K, |a, b| a.distance_sq(q).total_cmp(&b.distance_sq(q)));
+			black_box(&sorted[..K]);
+		}
+	});
+	common::time("brute range", QUERIES as u32, || {
+		for &q in &queries {
+			let aabb = Aabb::new(q, q + point![RADIUS, RADIUS]);
+			black_box(points.iter().filter(|p| aabb.contains(**p)).count());
+		}
+	});
+
+	let mut grid = HashGrid::new(RADIUS * 2.0);
+	let mut quadtree = Quadtree::new(Aabb::new(point![0.0, 0.0], point![100.0, 100.0]));
+	for (i, &p) in points.iter().enumerate() {
+		grid.insert(p, i);
+		quadtree.insert(p, i);
+	}
+	let (kdtree, _) = KdTree::from_points(points.iter().copied().zip(0..));
+
+	bench("grid", &grid, &queries);
+	bench("quadtree", &quadtree, &queries);
+	bench("kdtree", &kdtree, &queries);
+}
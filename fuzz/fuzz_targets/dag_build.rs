@@ -0,0 +1,39 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use lykoi_data::{dag::Dag, digraph::Digraph};
+
+// the first byte picks the amount of points, every pair of bytes after it is an edge.
+// the result of `Dag::build` is checked against `Digraph`, which finds cycles independently.
+fuzz_target!(|data: &[u8]| {
+	let Some((&len, data)) = data.split_first() else {
+		return;
+	};
+	let len = len as usize % 64 + 1;
+
+	let mut dag = Dag::new();
+	let mut graph = Digraph::new();
+	let points = (0..len).map(|x| dag.point(x)).collect::<Vec<_>>();
+	for x in 0..len {
+		let _ = graph.point(x);
+	}
+
+	for pair in data.chunks_exact(2) {
+		let a = &points[pair[0] as usize % len];
+		let b = &points[pair[1] as usize % len];
+		dag.depend(a, b);
+		graph.edge(a, b).unwrap();
+	}
+
+	match dag.order() {
+		Ok(order) => {
+			assert!(graph.is_acyclic());
+			assert!(dag.check_order(&order).is_ok());
+
+			let mut built = dag.build().unwrap();
+			built.sort();
+			assert_eq!(built, (0..len).collect::<Vec<_>>());
+		},
+		Err(_) => assert!(!graph.is_acyclic()),
+	}
+});
@@ -1,15 +1,33 @@
 
+// srgb transfer functions, from IEC 61966-2-1. negative values are mirrored, as in css color 4
+fn srgb_to_linear(x: f32) -> f32 {
+	let abs = x.abs();
+	if abs <= 0.04045 {
+		x / 12.92
+	} else {
+		((abs + 0.055) / 1.055).powf(2.4).copysign(x)
+	}
+}
+fn srgb_from_linear(x: f32) -> f32 {
+	let abs = x.abs();
+	if abs <= 0.0031308 {
+		x * 12.92
+	} else {
+		(1.055 * abs.powf(1.0 / 2.4) - 0.055).copysign(x)
+	}
+}
+
 /**
-rgb color
+gamma encoded srgb color, as used by hex codes and most image formats
 */
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct RGB {
+pub struct Srgb {
 	pub r: f32,
 	pub g: f32,
 	pub b: f32,
 }
 
-impl RGB {
+impl Srgb {
 	#[inline]
 	pub fn new(r: f32, g: f32, b: f32) -> Self {
 		Self {
@@ -19,88 +37,101 @@ impl RGB {
 		}
 	}
 
+	/// parses `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`, with an optional `#`. alpha is discarded, see [`RGBA::from_hex_str()`].
 	pub fn from_hex_str(hex: &str) -> Option<Self> {
-		let hex = hex.trim_start_matches("#");
-		match hex.len() {
-			3 => {
-				let (r, hex) = hex.split_at(1);
-				let (g, b) = hex.split_at(1);
-				let Ok(r)= u8::from_str_radix(r, 16) else {
-					return None;
-				};
-				let Ok(g)= u8::from_str_radix(g, 16) else {
-					return None;
-				};
-				let Ok(b)= u8::from_str_radix(b, 16) else {
-					return None;
-				};
-				let r = r as f32 * 17.0 / 255.0;
-				let g = g as f32 * 17.0 / 255.0;
-				let b = b as f32 * 17.0 / 255.0;
-				Some(Self::new(r, g, b))
-			},
-			4 => {
-				let (r, hex) = hex.split_at(1);
-				let (g, hex) = hex.split_at(1);
-				let (b, a) = hex.split_at(1);
-				let Ok(r)= u8::from_str_radix(r, 16) else {
-					return None;
-				};
-				let Ok(g)= u8::from_str_radix(g, 16) else {
-					return None;
-				};
-				let Ok(b)= u8::from_str_radix(b, 16) else {
-					return None;
-				};
-				let Ok(a)= u8::from_str_radix(a, 16) else {
-					return None;
-				};
-				let r = r as f32 * 17.0 / 255.0;
-				let g = g as f32 * 17.0 / 255.0;
-				let b = b as f32 * 17.0 / 255.0;
-				let _ = a as f32 * 17.0 / 255.0;
-				Some(Self::new(r, g, b))
-			},
-			6 => {
-				let (r, hex) = hex.split_at(2);
-				let (g, b) = hex.split_at(2);
-				let Ok(r)= u8::from_str_radix(r, 16) else {
-					return None;
-				};
-				let Ok(g)= u8::from_str_radix(g, 16) else {
-					return None;
-				};
-				let Ok(b)= u8::from_str_radix(b, 16) else {
-					return None;
-				};
-				let r = r as f32 / 255.0;
-				let g = g as f32 / 255.0;
-				let b = b as f32 / 255.0;
-				Some(Self::new(r, g, b))
-			},
-			8 => {
-				let (r, hex) = hex.split_at(2);
-				let (g, hex) = hex.split_at(2);
-				let (b, a) = hex.split_at(2);
-				let Ok(r)= u8::from_str_radix(r, 16) else {
-					return None;
-				};
-				let Ok(g)= u8::from_str_radix(g, 16) else {
-					return None;
-				};
-				let Ok(b)= u8::from_str_radix(b, 16) else {
-					return None;
-				};
-				let Ok(a)= u8::from_str_radix(a, 16) else {
-					return None;
-				};
-				let r = r as f32 / 255.0;
-				let g = g as f32 / 255.0;
-				let b = b as f32 / 255.0;
-				let _ = a as f32 / 255.0;
-				Some(Self::new(r, g, b))
-			},
-			_ => None
+		RGBA::from_hex_str(hex).map(RGBA::rgb)
+	}
+
+	#[inline]
+	pub fn with_alpha(self, a: f32) -> RGBA {
+		RGBA::new(self.r, self.g, self.b, a)
+	}
+
+	#[inline]
+	pub fn lerp(self, other: Srgb, t: f32) -> Self {
+		Self::new(
+			self.r + (other.r - self.r) * t,
+			self.g + (other.g - self.g) * t,
+			self.b + (other.b - self.b) * t,
+		)
+	}
+
+	#[inline]
+	pub fn get(self) -> (f32, f32, f32) {
+		(self.r, self.g, self.b)
+	}
+	#[inline]
+	pub fn borrow(&self) -> (&f32, &f32, &f32) {
+		(&self.r, &self.g, &self.b)
+	}
+	#[inline]
+	pub fn borrow_mut(&mut self) -> (&mut f32, &mut f32, &mut f32) {
+		(&mut self.r, &mut self.g, &mut self.b)
+	}
+
+	/// decodes into linear light.
+	#[inline]
+	pub fn to_linear(self) -> LinearRgb {
+		LinearRgb::new(srgb_to_linear(self.r), srgb_to_linear(self.g), srgb_to_linear(self.b))
+	}
+
+	#[inline]
+	pub fn to_oklab(self) -> OkLab {
+		self.to_linear().to_oklab()
+	}
+
+	pub fn to_hsl(self) -> Hsl {
+		let max = self.r.max(self.g).max(self.b);
+		let min = self.r.min(self.g).min(self.b);
+		let l = (max + min) / 2.0;
+		let s = if l <= 0.0 || l >= 1.0 {
+			0.0
+		} else {
+			(max - l) / l.min(1.0 - l)
+		};
+		Hsl::new(if s == 0.0 { 0.0 } else { hue(self, max, min) }, s, l)
+	}
+
+	pub fn to_hsv(self) -> Hsv {
+		let max = self.r.max(self.g).max(self.b);
+		let min = self.r.min(self.g).min(self.b);
+		let s = if max == 0.0 { 0.0 } else { (max - min) / max };
+		Hsv::new(if s == 0.0 { 0.0 } else { hue(self, max, min) }, s, max)
+	}
+
+	pub fn to_hwb(self) -> Hwb {
+		let max = self.r.max(self.g).max(self.b);
+		let min = self.r.min(self.g).min(self.b);
+		Hwb::new(hue(self, max, min), min, 1.0 - max)
+	}
+}
+
+impl Default for Srgb {
+	fn default() -> Self {
+		Self::new(0.0, 0.0, 0.0)
+	}
+}
+
+/// the same as [`Srgb`].
+pub type RGB = Srgb;
+
+/**
+linear light srgb color, for blending and color math
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinearRgb {
+	pub r: f32,
+	pub g: f32,
+	pub b: f32,
+}
+
+impl LinearRgb {
+	#[inline]
+	pub fn new(r: f32, g: f32, b: f32) -> Self {
+		Self {
+			r,
+			g,
+			b,
 		}
 	}
 
@@ -117,6 +148,12 @@ impl RGB {
 		(&mut self.r, &mut self.g, &mut self.b)
 	}
 
+	/// gamma encodes into srgb.
+	#[inline]
+	pub fn to_srgb(self) -> Srgb {
+		Srgb::new(srgb_from_linear(self.r), srgb_from_linear(self.g), srgb_from_linear(self.b))
+	}
+
 	#[expect(clippy::excessive_precision, reason="numbers taken directly from oklab blog")]
 	pub fn to_oklab(self) -> OkLab {
 		let r = self.r;
@@ -127,9 +164,9 @@ impl RGB {
 		let m = 0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b;
 		let s = 0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b;
 
-		let l = l.powf(1.0 / 3.0);
-		let m = m.powf(1.0 / 3.0);
-		let s = s.powf(1.0 / 3.0);
+		let l = l.cbrt();
+		let m = m.cbrt();
+		let s = s.cbrt();
 
 		OkLab {
 			l: 0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
@@ -139,7 +176,7 @@ impl RGB {
 	}
 }
 
-impl Default for RGB {
+impl Default for LinearRgb {
 	fn default() -> Self {
 		Self::new(0.0, 0.0, 0.0)
 	}
@@ -178,21 +215,49 @@ impl OkLab {
 		(&mut self.l, &mut self.a, &mut self.b)
 	}
 
-	#[expect(clippy::excessive_precision, reason="numbers taken directly from oklab blog")]
-	pub fn to_rgb(self) -> RGB {
-		let l = self.l;
-		let a = self.a;
-		let b = self.b;
+	#[inline]
+	pub fn with_alpha(self, alpha: f32) -> OkLaba {
+		OkLaba::new(self.l, self.a, self.b, alpha)
+	}
+
+	#[inline]
+	pub fn lerp(self, other: OkLab, t: f32) -> Self {
+		Self::new(
+			self.l + (other.l - self.l) * t,
+			self.a + (other.a - self.a) * t,
+			self.b + (other.b - self.b) * t,
+		)
+	}
 
-		let l = l + 0.3963377774 * a + 0.2158037573 * b;
-		let m = l - 0.1055613458 * a - 0.0638541728 * b;
-		let s = l - 0.0894841775 * a - 1.2914855480 * b;
+	/// the result may be outside of `0..=1`. see [`Self::to_gamut()`].
+	#[inline]
+	pub fn to_rgb(self) -> Srgb {
+		self.to_linear_rgb().to_srgb()
+	}
+
+	/// see [`OkLch::to_gamut()`].
+	#[inline]
+	pub fn to_gamut<C: Gamut>(self, mapping: GamutMapping) -> C {
+		self.to_oklch().to_gamut(mapping)
+	}
+
+	/// converts into polar form. achromatic colors have a hue of 0.
+	pub fn to_oklch(self) -> OkLch {
+		let (c, h) = to_polar(self.a, self.b, ACHROMATIC);
+		OkLch::new(self.l, c, h)
+	}
+
+	#[expect(clippy::excessive_precision, reason="numbers taken directly from oklab blog")]
+	pub fn to_linear_rgb(self) -> LinearRgb {
+		let l = self.l + 0.3963377774 * self.a + 0.2158037573 * self.b;
+		let m = self.l - 0.1055613458 * self.a - 0.0638541728 * self.b;
+		let s = self.l - 0.0894841775 * self.a - 1.2914855480 * self.b;
 
 		let l = l * l * l;
 		let m = m * m * m;
 		let s = s * s * s;
 
-		RGB {
+		LinearRgb {
 			r:  4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
 			g: -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
 			b: -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
@@ -206,6 +271,957 @@ impl Default for OkLab {
 	}
 }
 
+impl From<Srgb> for LinearRgb {
+	fn from(value: Srgb) -> Self {
+		value.to_linear()
+	}
+}
+impl From<LinearRgb> for Srgb {
+	fn from(value: LinearRgb) -> Self {
+		value.to_srgb()
+	}
+}
+impl From<Srgb> for OkLab {
+	fn from(value: Srgb) -> Self {
+		value.to_oklab()
+	}
+}
+impl From<OkLab> for Srgb {
+	fn from(value: OkLab) -> Self {
+		value.to_rgb()
+	}
+}
+impl From<LinearRgb> for OkLab {
+	fn from(value: LinearRgb) -> Self {
+		value.to_oklab()
+	}
+}
+impl From<OkLab> for LinearRgb {
+	fn from(value: OkLab) -> Self {
+		value.to_linear_rgb()
+	}
+}
+
+// chroma below this is treated as gray, as rounding error would otherwise give it an arbitrary hue
+const ACHROMATIC: f32 = 1e-6;
+
+// the hue of an rgb color in degrees, given its largest and smallest components, or 0 if it's gray
+fn hue(rgb: Srgb, max: f32, min: f32) -> f32 {
+	let d = max - min;
+	if d <= 0.0 {
+		return 0.0;
+	}
+	let h = if max == rgb.r {
+		(rgb.g - rgb.b) / d
+	} else if max == rgb.g {
+		(rgb.b - rgb.r) / d + 2.0
+	} else {
+		(rgb.r - rgb.g) / d + 4.0
+	};
+	(h * 60.0).rem_euclid(360.0)
+}
+
+// the boilerplate shared by simple three component colors. `@no_default` leaves out `Default`
+macro_rules! impl_components {
+	(@no_default $t:ident, $x:ident, $y:ident, $z:ident) => {
+		impl $t {
+			#[inline]
+			pub fn new($x: f32, $y: f32, $z: f32) -> Self {
+				Self {
+					$x,
+					$y,
+					$z,
+				}
+			}
+
+			#[inline]
+			pub fn get(self) -> (f32, f32, f32) {
+				(self.$x, self.$y, self.$z)
+			}
+			#[inline]
+			pub fn borrow(&self) -> (&f32, &f32, &f32) {
+				(&self.$x, &self.$y, &self.$z)
+			}
+			#[inline]
+			pub fn borrow_mut(&mut self) -> (&mut f32, &mut f32, &mut f32) {
+				(&mut self.$x, &mut self.$y, &mut self.$z)
+			}
+		}
+	};
+	($t:ident, $x:ident, $y:ident, $z:ident) => {
+		impl_components!(@no_default $t, $x, $y, $z);
+
+		impl Default for $t {
+			fn default() -> Self {
+				Self::new(0.0, 0.0, 0.0)
+			}
+		}
+	};
+}
+
+/**
+oklab color in polar form: lightness, chroma and hue in degrees
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OkLch {
+	pub l: f32,
+	pub c: f32,
+	pub h: f32,
+}
+impl_components!(OkLch, l, c, h);
+
+impl OkLch {
+	pub fn to_oklab(self) -> OkLab {
+		let (sin, cos) = self.h.to_radians().sin_cos();
+		OkLab::new(self.l, self.c * cos, self.c * sin)
+	}
+
+	/**
+	interpolates between two colors, taking the shorter way around the hue circle.
+
+	an achromatic color has no meaningful hue, so it takes the other color's hue instead.
+	*/
+	pub fn lerp(self, other: OkLch, t: f32) -> Self {
+		let (h0, h1) = match (self.c < ACHROMATIC, other.c < ACHROMATIC) {
+			(true, false) => (other.h, other.h),
+			(false, true) => (self.h, self.h),
+			_ => (self.h, other.h),
+		};
+		let delta = (h1 - h0 + 180.0).rem_euclid(360.0) - 180.0;
+		Self::new(
+			self.l + (other.l - self.l) * t,
+			self.c + (other.c - self.c) * t,
+			(h0 + delta * t).rem_euclid(360.0),
+		)
+	}
+
+	/**
+	converts into the gamut of `C`, such as [`Srgb`] or [`DisplayP3`]. colors already in gamut are converted unchanged.
+
+	```
+	# use lykoi_data::color::{ColorSpace, Gamut, GamutMapping, DisplayP3, OkLch, Srgb};
+	let red: OkLch = DisplayP3::new(1.0, 0.0, 0.0).convert();
+	assert!(!red.convert::<Srgb>().in_gamut());
+
+	let mapped: Srgb = red.to_gamut(GamutMapping::Perceptual);
+	assert!(mapped.in_gamut());
+	```
+	*/
+	pub fn to_gamut<C: Gamut>(self, mapping: GamutMapping) -> C {
+		// the just noticeable difference in oklab, and the precision of the chroma search
+		const JND: f32 = 0.02;
+		const EPSILON: f32 = 0.0001;
+
+		let origin: C = self.convert();
+		if mapping == GamutMapping::Clip || origin.in_gamut() {
+			return origin.clip();
+		}
+		if self.l >= 1.0 {
+			return OkLab::new(1.0, 0.0, 0.0).convert::<C>().clip();
+		}
+		if self.l <= 0.0 {
+			return OkLab::new(0.0, 0.0, 0.0).convert::<C>().clip();
+		}
+
+		let distance = |a: OkLab, b: OkLab| {
+			((a.l - b.l).powi(2) + (a.a - b.a).powi(2) + (a.b - b.b).powi(2)).sqrt()
+		};
+
+		let mut current = self;
+		let mut clipped = origin.clip();
+		if distance(clipped.convert(), current.to_oklab()) < JND {
+			return clipped;
+		}
+
+		let mut min = 0.0;
+		let mut max = self.c;
+		let mut min_in_gamut = true;
+		while max - min > EPSILON {
+			current.c = (min + max) / 2.0;
+			let candidate: C = current.convert();
+			if min_in_gamut && candidate.in_gamut() {
+				min = current.c;
+				continue;
+			}
+			clipped = candidate.clip();
+			let e = distance(clipped.convert(), current.to_oklab());
+			if e < JND {
+				if JND - e < EPSILON {
+					return clipped;
+				}
+				min_in_gamut = false;
+				min = current.c;
+			} else {
+				max = current.c;
+			}
+		}
+		clipped
+	}
+}
+
+/**
+srgb color as hue in degrees, saturation and lightness. achromatic colors have a hue of 0
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsl {
+	pub h: f32,
+	pub s: f32,
+	pub l: f32,
+}
+impl_components!(Hsl, h, s, l);
+
+impl Hsl {
+	pub fn to_srgb(self) -> Srgb {
+		let a = self.s * self.l.min(1.0 - self.l);
+		let f = |n: f32| {
+			let k = (n + self.h / 30.0).rem_euclid(12.0);
+			self.l - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+		};
+		Srgb::new(f(0.0), f(8.0), f(4.0))
+	}
+}
+
+/**
+srgb color as hue in degrees, saturation and value. achromatic colors have a hue of 0
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsv {
+	pub h: f32,
+	pub s: f32,
+	pub v: f32,
+}
+impl_components!(Hsv, h, s, v);
+
+impl Hsv {
+	pub fn to_srgb(self) -> Srgb {
+		let f = |n: f32| {
+			let k = (n + self.h / 60.0).rem_euclid(6.0);
+			self.v - self.v * self.s * k.min(4.0 - k).clamp(0.0, 1.0)
+		};
+		Srgb::new(f(5.0), f(3.0), f(1.0))
+	}
+}
+
+/**
+srgb color as hue in degrees, whiteness and blackness
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hwb {
+	pub h: f32,
+	pub w: f32,
+	pub b: f32,
+}
+impl_components!(@no_default Hwb, h, w, b);
+
+impl Hwb {
+	/// whiteness and blackness adding to more than 1 give a gray, scaled so they add to 1.
+	pub fn to_srgb(self) -> Srgb {
+		if self.w + self.b >= 1.0 {
+			let gray = self.w / (self.w + self.b);
+			return Srgb::new(gray, gray, gray);
+		}
+		let pure = Hsl::new(self.h, 1.0, 0.5).to_srgb();
+		let scale = 1.0 - self.w - self.b;
+		Srgb::new(pure.r * scale + self.w, pure.g * scale + self.w, pure.b * scale + self.w)
+	}
+}
+
+impl Default for Hwb {
+	fn default() -> Self {
+		Self::new(0.0, 0.0, 1.0)
+	}
+}
+
+macro_rules! impl_from {
+	($($from:ty => $to:ty, $method:ident;)*) => {
+		$(
+			impl From<$from> for $to {
+				fn from(value: $from) -> Self {
+					value.$method()
+				}
+			}
+		)*
+	};
+	// conversions that pass through another space
+	(@via $via:ty: $($from:ty => [$($to:ty),*];)*) => {
+		$($(
+			impl From<$from> for $to {
+				fn from(value: $from) -> Self {
+					<$to>::from(<$via>::from(value))
+				}
+			}
+		)*)*
+	};
+}
+
+impl_from! {
+	OkLab => OkLch, to_oklch;
+	OkLch => OkLab, to_oklab;
+	Srgb => Hsl, to_hsl;
+	Hsl => Srgb, to_srgb;
+	Srgb => Hsv, to_hsv;
+	Hsv => Srgb, to_srgb;
+	Srgb => Hwb, to_hwb;
+	Hwb => Srgb, to_srgb;
+}
+
+impl_from! {
+	@via OkLab:
+	OkLch => [Srgb, LinearRgb];
+	Srgb => [OkLch];
+	LinearRgb => [OkLch];
+}
+
+impl_from! {
+	@via Srgb:
+	Hsl => [LinearRgb, OkLab, OkLch, Hsv, Hwb];
+	Hsv => [LinearRgb, OkLab, OkLch, Hsl, Hwb];
+	Hwb => [LinearRgb, OkLab, OkLch, Hsl, Hsv];
+	LinearRgb => [Hsl, Hsv, Hwb];
+	OkLab => [Hsl, Hsv, Hwb];
+	OkLch => [Hsl, Hsv, Hwb];
+}
+
+// multiplies a 3x3 row-major matrix by a column vector
+fn mul3(m: &[[f32; 3]; 3], v: [f32; 3]) -> [f32; 3] {
+	std::array::from_fn(|i| m[i][0] * v[0] + m[i][1] * v[1] + m[i][2] * v[2])
+}
+
+// converts lab-like `a` and `b` into chroma and hue in degrees, with gray colors given a hue of 0
+fn to_polar(a: f32, b: f32, achromatic: f32) -> (f32, f32) {
+	let c = (a * a + b * b).sqrt();
+	if c < achromatic {
+		(c, 0.0)
+	} else {
+		(c, b.atan2(a).to_degrees().rem_euclid(360.0))
+	}
+}
+
+/**
+a color that can be converted through cie xyz, so any color space can be converted to any other.
+
+```
+# use lykoi_data::color::{ColorSpace, Srgb, Lab, DisplayP3};
+let lab: Lab = Srgb::new(1.0, 0.0, 0.0).convert();
+assert!((lab.l - 54.29).abs() < 0.01);
+
+let p3: DisplayP3 = lab.convert();
+assert!(p3.r < 1.0);
+```
+*/
+pub trait ColorSpace: Copy {
+	fn to_xyz(self) -> Xyz;
+	fn from_xyz(xyz: Xyz) -> Self;
+
+	#[inline]
+	fn convert<C: ColorSpace>(self) -> C {
+		C::from_xyz(self.to_xyz())
+	}
+}
+
+/**
+cie 1931 xyz color, relative to the d65 white point
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Xyz {
+	pub x: f32,
+	pub y: f32,
+	pub z: f32,
+}
+impl_components!(Xyz, x, y, z);
+
+impl Xyz {
+	/// adapts to the d50 white point, using the bradford transform.
+	#[expect(clippy::excessive_precision, reason="numbers taken directly from css color 4")]
+	pub fn to_d50(self) -> XyzD50 {
+		const M: [[f32; 3]; 3] = [
+			[ 1.0479297925449969, 0.022946870601609652, -0.05019226628920524],
+			[ 0.02962780877005599, 0.9904344267538799, -0.017073799063418826],
+			[-0.009243040646204504, 0.015055191490298152, 0.7518742814281371],
+		];
+		let [x, y, z] = mul3(&M, [self.x, self.y, self.z]);
+		XyzD50::new(x, y, z)
+	}
+}
+
+/**
+cie 1931 xyz color, relative to the d50 white point used by cielab and print
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct XyzD50 {
+	pub x: f32,
+	pub y: f32,
+	pub z: f32,
+}
+impl_components!(XyzD50, x, y, z);
+
+impl XyzD50 {
+	/// adapts to the d65 white point, using the bradford transform.
+	#[expect(clippy::excessive_precision, reason="numbers taken directly from css color 4")]
+	pub fn to_d65(self) -> Xyz {
+		const M: [[f32; 3]; 3] = [
+			[ 0.955473421488075, -0.02309845494876471, 0.06325924320057072],
+			[-0.0283697093338637, 1.0099953980813041, 0.021041441191917323],
+			[ 0.012314014864481998, -0.020507649298898964, 1.330365926242124],
+		];
+		let [x, y, z] = mul3(&M, [self.x, self.y, self.z]);
+		Xyz::new(x, y, z)
+	}
+
+	pub fn to_lab(self) -> Lab {
+		let f = |v: f32| if v > LAB_E { v.cbrt() } else { (LAB_K * v + 16.0) / 116.0 };
+		let [fx, fy, fz] = [self.x / D50[0], self.y / D50[1], self.z / D50[2]].map(f);
+		Lab::new(116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz))
+	}
+}
+
+// the d50 white point, and the cielab constants, from css color 4
+const D50: [f32; 3] = [0.3457 / 0.3585, 1.0, (1.0 - 0.3457 - 0.3585) / 0.3585];
+const LAB_E: f32 = 216.0 / 24389.0;
+const LAB_K: f32 = 24389.0 / 27.0;
+
+/**
+cielab color, relative to d50. lightness is from 0 to 100
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lab {
+	pub l: f32,
+	pub a: f32,
+	pub b: f32,
+}
+impl_components!(Lab, l, a, b);
+
+impl Lab {
+	pub fn to_xyz_d50(self) -> XyzD50 {
+		let fy = (self.l + 16.0) / 116.0;
+		let fx = self.a / 500.0 + fy;
+		let fz = fy - self.b / 200.0;
+		let f = |v: f32| if v * v * v > LAB_E { v * v * v } else { (116.0 * v - 16.0) / LAB_K };
+		let y = if self.l > LAB_K * LAB_E { fy * fy * fy } else { self.l / LAB_K };
+		XyzD50::new(f(fx) * D50[0], y * D50[1], f(fz) * D50[2])
+	}
+
+	/// converts into polar form. achromatic colors have a hue of 0.
+	pub fn to_lch(self) -> Lch {
+		let (c, h) = to_polar(self.a, self.b, ACHROMATIC * 100.0);
+		Lch::new(self.l, c, h)
+	}
+}
+
+/**
+cielab color in polar form: lightness, chroma and hue in degrees
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lch {
+	pub l: f32,
+	pub c: f32,
+	pub h: f32,
+}
+impl_components!(Lch, l, c, h);
+
+impl Lch {
+	pub fn to_lab(self) -> Lab {
+		let (sin, cos) = self.h.to_radians().sin_cos();
+		Lab::new(self.l, self.c * cos, self.c * sin)
+	}
+}
+
+// how far outside of `0..=1` a component can be while still counting as in gamut, to allow for rounding error
+const GAMUT_EPSILON: f32 = 1e-5;
+
+/**
+a color space with a limited gamut, which colors can be mapped into. see [`OkLch::to_gamut()`].
+*/
+pub trait Gamut: ColorSpace {
+	/// returns `true` if the color can be displayed, meaning every component is within `0..=1`.
+	fn in_gamut(self) -> bool;
+	/// clamps every component into `0..=1`. fast, but can visibly change hue and lightness.
+	fn clip(self) -> Self;
+}
+
+macro_rules! impl_gamut {
+	($($t:ty),*) => {
+		$(
+			impl Gamut for $t {
+				#[inline]
+				fn in_gamut(self) -> bool {
+					[self.r, self.g, self.b].iter().all(|x| (-GAMUT_EPSILON..=1.0 + GAMUT_EPSILON).contains(x))
+				}
+				#[inline]
+				fn clip(self) -> Self {
+					Self::new(self.r.clamp(0.0, 1.0), self.g.clamp(0.0, 1.0), self.b.clamp(0.0, 1.0))
+				}
+			}
+		)*
+	};
+}
+
+impl_gamut!(Srgb, LinearRgb);
+
+/// how [`OkLch::to_gamut()`] brings a color into gamut.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GamutMapping {
+	/// clamps each component. fast, but can shift hue and lightness.
+	Clip,
+	/// reduces chroma, keeping lightness and hue, using the css color 4 algorithm.
+	Perceptual,
+}
+
+// implements `ColorSpace` for a linear rgb space, given its matrices to and from xyz
+macro_rules! impl_linear_space {
+	($linear:ident, $to_xyz:expr, $from_xyz:expr $(,)?) => {
+		impl ColorSpace for $linear {
+			#[expect(clippy::excessive_precision, reason="numbers taken directly from css color 4")]
+			fn to_xyz(self) -> Xyz {
+				const M: [[f32; 3]; 3] = $to_xyz;
+				let [x, y, z] = mul3(&M, [self.r, self.g, self.b]);
+				Xyz::new(x, y, z)
+			}
+			#[expect(clippy::excessive_precision, reason="numbers taken directly from css color 4")]
+			fn from_xyz(xyz: Xyz) -> Self {
+				const M: [[f32; 3]; 3] = $from_xyz;
+				let [r, g, b] = mul3(&M, [xyz.x, xyz.y, xyz.z]);
+				Self::new(r, g, b)
+			}
+		}
+	};
+}
+
+// declares a gamma encoded rgb space and its linear counterpart, converting to xyz with the given matrices
+macro_rules! rgb_space {
+	(
+		$(#[$doc:meta])* $encoded:ident, $to_encoded:ident,
+		$(#[$linear_doc:meta])* $linear:ident,
+		$decode:expr, $encode:expr,
+		$to_xyz:expr, $from_xyz:expr $(,)?
+	) => {
+		$(#[$doc])*
+		#[derive(Debug, Clone, Copy, PartialEq)]
+		pub struct $encoded {
+			pub r: f32,
+			pub g: f32,
+			pub b: f32,
+		}
+		impl_components!($encoded, r, g, b);
+
+		impl $encoded {
+			/// decodes into linear light.
+			#[inline]
+			pub fn to_linear(self) -> $linear {
+				let decode: fn(f32) -> f32 = $decode;
+				$linear::new(decode(self.r), decode(self.g), decode(self.b))
+			}
+		}
+
+		$(#[$linear_doc])*
+		#[derive(Debug, Clone, Copy, PartialEq)]
+		pub struct $linear {
+			pub r: f32,
+			pub g: f32,
+			pub b: f32,
+		}
+		impl_components!($linear, r, g, b);
+
+		impl $linear {
+			/// gamma encodes.
+			#[inline]
+			pub fn $to_encoded(self) -> $encoded {
+				let encode: fn(f32) -> f32 = $encode;
+				$encoded::new(encode(self.r), encode(self.g), encode(self.b))
+			}
+		}
+
+		impl_linear_space!($linear, $to_xyz, $from_xyz);
+
+		impl_gamut!($encoded, $linear);
+
+		impl From<$encoded> for $linear {
+			fn from(value: $encoded) -> Self {
+				value.to_linear()
+			}
+		}
+		impl From<$linear> for $encoded {
+			fn from(value: $linear) -> Self {
+				value.$to_encoded()
+			}
+		}
+	};
+}
+
+rgb_space! {
+	/**
+	gamma encoded display p3 color, a wide gamut space used by many modern displays
+	*/
+	DisplayP3, to_display_p3,
+	/**
+	linear light display p3 color
+	*/
+	LinearDisplayP3,
+	srgb_to_linear, srgb_from_linear,
+	[
+		[0.4865709486482162, 0.26566769316909306, 0.1982172852343625],
+		[0.2289745640697488, 0.6917385218365064, 0.079286914093745],
+		[0.0, 0.04511338185890264, 1.043944368900976],
+	],
+	[
+		[ 2.493496911941425, -0.9313836179191239, -0.40271078445071684],
+		[-0.8294889695615747, 1.7626640603183463, 0.023624685841943577],
+		[ 0.03584583024378447, -0.07617238926804182, 0.9568845240076872],
+	],
+}
+
+// the rec. 2020 transfer function constants
+#[expect(clippy::excessive_precision, reason="numbers taken directly from css color 4")]
+const REC2020_A: f32 = 1.09929682680944;
+#[expect(clippy::excessive_precision, reason="numbers taken directly from css color 4")]
+const REC2020_B: f32 = 0.018053968510807;
+
+rgb_space! {
+	/**
+	gamma encoded rec. 2020 color, the very wide gamut space used by uhd and hdr video
+	*/
+	Rec2020, to_rec2020,
+	/**
+	linear light rec. 2020 color
+	*/
+	LinearRec2020,
+	|x| {
+		let abs = x.abs();
+		if abs < REC2020_B * 4.5 {
+			x / 4.5
+		} else {
+			((abs + REC2020_A - 1.0) / REC2020_A).powf(1.0 / 0.45).copysign(x)
+		}
+	},
+	|x| {
+		let abs = x.abs();
+		if abs < REC2020_B {
+			x * 4.5
+		} else {
+			(REC2020_A * abs.powf(0.45) - (REC2020_A - 1.0)).copysign(x)
+		}
+	},
+	[
+		[0.6369580483012914, 0.14461690358620832, 0.1688809751641721],
+		[0.2627002120112671, 0.6779980715188708, 0.05930171646986196],
+		[0.0, 0.028072693049087428, 1.060985057710791],
+	],
+	[
+		[ 1.716651187971268, -0.355670783776392, -0.253366281373660],
+		[-0.666684351832489, 1.616481236634939, 0.0157685458139111],
+		[ 0.017639857445311, -0.042770613257809, 0.942103121235474],
+	],
+}
+
+rgb_space! {
+	/**
+	gamma encoded adobe rgb (1998) color, a wide gamut space used in photography and print
+	*/
+	AdobeRgb, to_adobe_rgb,
+	/**
+	linear light adobe rgb (1998) color
+	*/
+	LinearAdobeRgb,
+	|x| x.abs().powf(563.0 / 256.0).copysign(x),
+	|x| x.abs().powf(256.0 / 563.0).copysign(x),
+	[
+		[0.5766690429101305, 0.1855582379065463, 0.1882286462349947],
+		[0.29734497525053605, 0.6273635662554661, 0.07529145849399788],
+		[0.02703136138641234, 0.07068885253582723, 0.9913375368376388],
+	],
+	[
+		[ 2.0415879038107465, -0.5650069742788596, -0.34473135077832956],
+		[-0.9692436362808795, 1.8759675015077202, 0.04155505740717557],
+		[ 0.013444280632031142, -0.11836239223101838, 1.0151749943912054],
+	],
+}
+
+impl ColorSpace for Xyz {
+	#[inline]
+	fn to_xyz(self) -> Xyz {
+		self
+	}
+	#[inline]
+	fn from_xyz(xyz: Xyz) -> Self {
+		xyz
+	}
+}
+
+// srgb is declared by hand above, but shares its conversion with the other rgb spaces
+impl_linear_space! {
+	LinearRgb,
+	[
+		[0.41239079926595934, 0.357584339383878, 0.1804807884018343],
+		[0.21263900587151027, 0.715168678767756, 0.07219231536073371],
+		[0.01933081871559182, 0.11919477979462598, 0.9505321522496607],
+	],
+	[
+		[ 3.2409699419045226, -1.537383177570094, -0.4986107602930034],
+		[-0.9692436362808796, 1.8759675015077202, 0.04155505740717559],
+		[ 0.05563007969699366, -0.20397695888897652, 1.0569715142428786],
+	],
+}
+
+// implements `ColorSpace` by converting through another space that implements it
+macro_rules! impl_space_via {
+	($($t:ty => $via:ty;)*) => {
+		$(
+			impl ColorSpace for $t {
+				#[inline]
+				fn to_xyz(self) -> Xyz {
+					<$via>::from(self).to_xyz()
+				}
+				#[inline]
+				fn from_xyz(xyz: Xyz) -> Self {
+					Self::from(<$via>::from_xyz(xyz))
+				}
+			}
+		)*
+	};
+}
+
+impl_space_via! {
+	Srgb => LinearRgb;
+	OkLab => LinearRgb;
+	OkLch => OkLab;
+	Hsl => Srgb;
+	Hsv => Srgb;
+	Hwb => Srgb;
+	XyzD50 => Xyz;
+	Lab => XyzD50;
+	Lch => Lab;
+	DisplayP3 => LinearDisplayP3;
+	Rec2020 => LinearRec2020;
+	AdobeRgb => LinearAdobeRgb;
+}
+
+impl_from! {
+	Xyz => XyzD50, to_d50;
+	XyzD50 => Xyz, to_d65;
+	XyzD50 => Lab, to_lab;
+	Lab => XyzD50, to_xyz_d50;
+	Lab => Lch, to_lch;
+	Lch => Lab, to_lab;
+}
+
+// every other space converts to and from xyz through `ColorSpace`
+macro_rules! impl_from_xyz {
+	($($t:ty),*) => {
+		$(
+			impl From<$t> for Xyz {
+				fn from(value: $t) -> Self {
+					value.to_xyz()
+				}
+			}
+			impl From<Xyz> for $t {
+				fn from(value: Xyz) -> Self {
+					<$t>::from_xyz(value)
+				}
+			}
+		)*
+	};
+}
+
+impl_from_xyz!(
+	Srgb, LinearRgb, OkLab, OkLch, Hsl, Hsv, Hwb, Lab, Lch,
+	DisplayP3, LinearDisplayP3, Rec2020, LinearRec2020, AdobeRgb, LinearAdobeRgb
+);
+
+// parses each component of a hex color, with alpha defaulting to 1
+fn parse_hex(hex: &str) -> Option<[f32; 4]> {
+	let hex = hex.trim_start_matches("#");
+	if !hex.is_ascii() {
+		return None;
+	}
+	let (width, scale) = match hex.len() {
+		3 | 4 => (1, 15.0),
+		6 | 8 => (2, 255.0),
+		_ => return None,
+	};
+	let mut out = [1.0; 4];
+	for (i, x) in out.iter_mut().enumerate().take(hex.len() / width) {
+		let Ok(v) = u8::from_str_radix(&hex[i * width..(i + 1) * width], 16) else {
+			return None;
+		};
+		*x = v as f32 / scale;
+	}
+	Some(out)
+}
+
+// interpolates premultiplied components, so transparent colors don't bleed into the result
+fn lerp_alpha(c0: [f32; 3], a0: f32, c1: [f32; 3], a1: f32, t: f32) -> ([f32; 3], f32) {
+	let a = a0 + (a1 - a0) * t;
+	if a == 0.0 {
+		return ([0.0; 3], 0.0);
+	}
+	let c = std::array::from_fn(|i| {
+		let x0 = c0[i] * a0;
+		let x1 = c1[i] * a1;
+		(x0 + (x1 - x0) * t) / a
+	});
+	(c, a)
+}
+
+/**
+srgb color with straight (not premultiplied) alpha
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RGBA {
+	pub r: f32,
+	pub g: f32,
+	pub b: f32,
+	pub a: f32,
+}
+
+impl RGBA {
+	#[inline]
+	pub fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+		Self {
+			r,
+			g,
+			b,
+			a,
+		}
+	}
+
+	/// parses `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`, with an optional `#`. alpha defaults to 1.
+	pub fn from_hex_str(hex: &str) -> Option<Self> {
+		let [r, g, b, a] = parse_hex(hex)?;
+		Some(Self::new(r, g, b, a))
+	}
+
+	#[inline]
+	pub fn get(self) -> (f32, f32, f32, f32) {
+		(self.r, self.g, self.b, self.a)
+	}
+	#[inline]
+	pub fn borrow(&self) -> (&f32, &f32, &f32, &f32) {
+		(&self.r, &self.g, &self.b, &self.a)
+	}
+	#[inline]
+	pub fn borrow_mut(&mut self) -> (&mut f32, &mut f32, &mut f32, &mut f32) {
+		(&mut self.r, &mut self.g, &mut self.b, &mut self.a)
+	}
+
+	/// the color without its alpha.
+	#[inline]
+	pub fn rgb(self) -> Srgb {
+		Srgb::new(self.r, self.g, self.b)
+	}
+
+	/// multiplies the color by its alpha. the result should only be used with [`Self::unpremultiply()`].
+	#[inline]
+	pub fn premultiply(self) -> Self {
+		Self::new(self.r * self.a, self.g * self.a, self.b * self.a, self.a)
+	}
+	/// divides a premultiplied color by its alpha. fully transparent colors become transparent black.
+	#[inline]
+	pub fn unpremultiply(self) -> Self {
+		if self.a == 0.0 {
+			return Self::default();
+		}
+		Self::new(self.r / self.a, self.g / self.a, self.b / self.a, self.a)
+	}
+
+	/// composites `self` on top of `below`, using porter-duff "source over".
+	pub fn over(self, below: RGBA) -> Self {
+		let a = self.a + below.a * (1.0 - self.a);
+		if a == 0.0 {
+			return Self::default();
+		}
+		let mix = |top: f32, bottom: f32| (top * self.a + bottom * below.a * (1.0 - self.a)) / a;
+		Self::new(mix(self.r, below.r), mix(self.g, below.g), mix(self.b, below.b), a)
+	}
+
+	/// interpolates between two colors. color is interpolated premultiplied, so transparent colors don't tint the result.
+	pub fn lerp(self, other: RGBA, t: f32) -> Self {
+		let ([r, g, b], a) = lerp_alpha([self.r, self.g, self.b], self.a, [other.r, other.g, other.b], other.a, t);
+		Self::new(r, g, b, a)
+	}
+
+	#[inline]
+	pub fn to_oklaba(self) -> OkLaba {
+		self.rgb().to_oklab().with_alpha(self.a)
+	}
+}
+
+impl Default for RGBA {
+	fn default() -> Self {
+		Self::new(0.0, 0.0, 0.0, 0.0)
+	}
+}
+
+impl From<Srgb> for RGBA {
+	fn from(value: Srgb) -> Self {
+		value.with_alpha(1.0)
+	}
+}
+
+/**
+oklab color with straight alpha
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OkLaba {
+	pub l: f32,
+	pub a: f32,
+	pub b: f32,
+	pub alpha: f32,
+}
+
+impl OkLaba {
+	#[inline]
+	pub fn new(l: f32, a: f32, b: f32, alpha: f32) -> Self {
+		Self {
+			l,
+			a,
+			b,
+			alpha,
+		}
+	}
+
+	#[inline]
+	pub fn get(self) -> (f32, f32, f32, f32) {
+		(self.l, self.a, self.b, self.alpha)
+	}
+	#[inline]
+	pub fn borrow(&self) -> (&f32, &f32, &f32, &f32) {
+		(&self.l, &self.a, &self.b, &self.alpha)
+	}
+	#[inline]
+	pub fn borrow_mut(&mut self) -> (&mut f32, &mut f32, &mut f32, &mut f32) {
+		(&mut self.l, &mut self.a, &mut self.b, &mut self.alpha)
+	}
+
+	/// the color without its alpha.
+	#[inline]
+	pub fn oklab(self) -> OkLab {
+		OkLab::new(self.l, self.a, self.b)
+	}
+
+	/// interpolates between two colors. color is interpolated premultiplied, so transparent colors don't tint the result.
+	pub fn lerp(self, other: OkLaba, t: f32) -> Self {
+		let ([l, a, b], alpha) = lerp_alpha([self.l, self.a, self.b], self.alpha, [other.l, other.a, other.b], other.alpha, t);
+		Self::new(l, a, b, alpha)
+	}
+
+	#[inline]
+	pub fn to_rgba(self) -> RGBA {
+		self.oklab().to_rgb().with_alpha(self.alpha)
+	}
+}
+
+impl Default for OkLaba {
+	fn default() -> Self {
+		Self::new(0.0, 0.0, 0.0, 0.0)
+	}
+}
+
+impl From<OkLab> for OkLaba {
+	fn from(value: OkLab) -> Self {
+		value.with_alpha(1.0)
+	}
+}
+
 
 #[cfg(test)]
 mod test {
@@ -219,6 +1235,277 @@ mod test {
 		assert_eq!(c.get(), (1.0, 1.0, 1.0));
 		let c = color::RGB::from_hex_str("#ff00ff").expect("parse failed");
 		assert_eq!(c.get(), (1.0, 0.0, 1.0));
+		assert_eq!(color::RGB::from_hex_str("#ff00f"), None);
+		assert_eq!(color::RGB::from_hex_str("#ff00fg"), None);
+	}
+
+	fn approx(a: (f32, f32, f32), b: (f32, f32, f32), epsilon: f32) -> bool {
+		(a.0 - b.0).abs() < epsilon && (a.1 - b.1).abs() < epsilon && (a.2 - b.2).abs() < epsilon
+	}
+
+	#[test]
+	fn test_oklab() {
+		// reference values from css color 4
+		let reference = [
+			("#ffffff", (1.0, 0.0, 0.0)),
+			("#000000", (0.0, 0.0, 0.0)),
+			("#ff0000", (0.627955, 0.224863, 0.125846)),
+			("#00ff00", (0.866440, -0.233888, 0.179498)),
+			("#0000ff", (0.452014, -0.032457, -0.311528)),
+			("#808080", (0.599871, 0.0, 0.0)),
+			("#ffff00", (0.967983, -0.071369, 0.198570)),
+		];
+		for (hex, lab) in reference {
+			let c = color::Srgb::from_hex_str(hex).expect("parse failed");
+			assert!(approx(c.to_oklab().get(), lab, 1e-3), "{hex}: {:?} != {lab:?}", c.to_oklab());
+			assert!(approx(color::OkLab::new(lab.0, lab.1, lab.2).to_rgb().get(), c.get(), 1e-3), "{hex}");
+		}
+	}
+
+	#[test]
+	fn test_transfer() {
+		let c = color::Srgb::new(0.5, 0.02, 1.0);
+		assert!(approx(c.to_linear().get(), (0.21404114, 0.0015479876, 1.0), 1e-6));
+		assert!(approx(c.to_linear().to_srgb().get(), c.get(), 1e-6));
+		for i in 0..=255 {
+			let x = i as f32 / 255.0;
+			let c = color::Srgb::new(x, -x, x);
+			assert!(approx(c.to_linear().to_srgb().get(), c.get(), 1e-5));
+			assert!(approx(color::OkLab::from(c).to_linear_rgb().get(), c.to_linear().get(), 1e-4));
+		}
+	}
+
+	#[test]
+	fn test_hue() {
+		let c = color::Srgb::new(1.0, 0.5, 0.0);
+		assert!(approx(c.to_hsl().get(), (30.0, 1.0, 0.5), 1e-4));
+		assert!(approx(c.to_hsv().get(), (30.0, 1.0, 1.0), 1e-4));
+		assert!(approx(c.to_hwb().get(), (30.0, 0.0, 0.0), 1e-4));
+
+		let c = color::Srgb::new(0.2, 0.4, 0.8);
+		assert!(approx(c.to_hsl().get(), (220.0, 0.6, 0.5), 1e-4));
+		assert!(approx(c.to_hsv().get(), (220.0, 0.75, 0.8), 1e-4));
+		assert!(approx(c.to_hwb().get(), (220.0, 0.2, 0.2), 1e-4));
+		assert!(approx(color::Srgb::new(1.0, 0.0, 0.5).to_hsl().get(), (330.0, 1.0, 0.5), 1e-4));
+
+		// grays have no hue
+		for x in [0.0, 0.3, 1.0] {
+			let gray = color::Srgb::new(x, x, x);
+			assert_eq!(gray.to_hsl().get(), (0.0, 0.0, x));
+			assert_eq!(gray.to_hsv().get(), (0.0, 0.0, x));
+			assert_eq!(gray.to_hwb().get(), (0.0, x, 1.0 - x));
+			assert_eq!(color::OkLch::from(gray).h, 0.0);
+			assert!(color::OkLch::from(gray).c < 1e-6);
+		}
+		assert_eq!(color::Hwb::new(120.0, 0.6, 0.6).to_srgb().get(), (0.5, 0.5, 0.5));
+
+		let red = color::OkLch::from(color::Srgb::new(1.0, 0.0, 0.0));
+		assert!(approx(red.get(), (0.627955, 0.257683, 29.2339), 1e-3));
+
+		// an achromatic endpoint keeps the other's hue
+		let white = color::OkLch::new(1.0, 0.0, 0.0);
+		assert_eq!(white.lerp(red, 0.5).h, red.h);
+		assert_eq!(red.lerp(white, 0.5).h, red.h);
+		// the shorter way around
+		let a = color::OkLch::new(0.5, 0.1, 350.0);
+		let b = color::OkLch::new(0.5, 0.1, 30.0);
+		assert!((a.lerp(b, 0.5).h - 10.0).abs() < 1e-4);
+		assert!((b.lerp(a, 0.25).h - 20.0).abs() < 1e-4);
+	}
+
+	#[cfg(feature = "rng")]
+	#[test]
+	fn test_round_trip() {
+		use crate::rng::XorShift64;
+
+		let mut rng = XorShift64::new(0xc010);
+
+		for _ in 0..1000 {
+			let c = color::Srgb::new(rng.nextf() as f32, rng.nextf() as f32, rng.nextf() as f32);
+			assert!(approx(color::Srgb::from(color::Hsl::from(c)).get(), c.get(), 1e-5), "{c:?}");
+			assert!(approx(color::Srgb::from(color::Hsv::from(c)).get(), c.get(), 1e-5), "{c:?}");
+			assert!(approx(color::Srgb::from(color::Hwb::from(c)).get(), c.get(), 1e-5), "{c:?}");
+			assert!(approx(color::Srgb::from(color::OkLch::from(c)).get(), c.get(), 1e-4), "{c:?}");
+			assert!(approx(color::Srgb::from(color::LinearRgb::from(c)).get(), c.get(), 1e-5), "{c:?}");
+
+			// through every pair of spaces
+			let hsv = color::Hsv::from(color::Hwb::from(color::Hsl::from(c)));
+			assert!(approx(color::Srgb::from(color::OkLch::from(hsv)).get(), c.get(), 1e-4), "{c:?}");
+
+			let lab = c.to_oklab();
+			assert!(approx(lab.to_oklch().to_oklab().get(), lab.get(), 1e-6), "{lab:?}");
+
+			let hsl = color::Hsl::new(rng.range(0.0, 360.0) as f32, rng.range(0.01, 1.0) as f32, rng.range(0.01, 0.99) as f32);
+			let back = color::Hsl::from(hsl.to_srgb());
+			// rounding error in rgb grows as the chroma shrinks, so saturation and hue are scaled by it
+			let chroma = 2.0 * hsl.s * hsl.l.min(1.0 - hsl.l);
+			let dh = ((back.h - hsl.h + 180.0).rem_euclid(360.0) - 180.0).abs();
+			assert!((back.l - hsl.l).abs() < 1e-6, "{hsl:?} != {back:?}");
+			assert!((back.s - hsl.s).abs() * chroma < 1e-6, "{hsl:?} != {back:?}");
+			assert!(dh * chroma < 1e-4, "{hsl:?} != {back:?}");
+		}
+	}
+
+	#[test]
+	fn test_xyz() {
+		use color::ColorSpace;
+
+		let red = color::Srgb::new(1.0, 0.0, 0.0);
+		assert!(approx(red.to_xyz().get(), (0.412391, 0.212639, 0.019331), 1e-5));
+		let white = color::Srgb::new(1.0, 1.0, 1.0);
+		assert!(approx(white.to_xyz().get(), (0.950456, 1.0, 1.089058), 1e-4));
+		assert!(approx(white.to_xyz().to_d50().get(), (0.964296, 1.0, 0.825105), 1e-4));
+		assert!(approx(color::AdobeRgb::new(1.0, 1.0, 1.0).to_xyz().get(), white.to_xyz().get(), 1e-4));
+
+		// reference values from css color 4
+		assert!(approx(red.convert::<color::Lab>().get(), (54.2905, 80.8049, 69.8910), 1e-2));
+		assert!(approx(red.convert::<color::Lch>().get(), (54.2905, 106.839, 40.8525), 1e-2));
+		assert!(approx(white.convert::<color::Lab>().get(), (100.0, 0.0, 0.0), 1e-2));
+		assert_eq!(white.convert::<color::Lch>().h, 0.0);
+		assert!(approx(color::DisplayP3::new(1.0, 0.0, 0.0).convert::<color::Srgb>().get(), (1.0931, -0.2267, -0.1501), 1e-3));
+		assert!(approx(color::LinearRec2020::new(1.0, 0.0, 0.0).convert::<color::LinearRgb>().get(), (1.6605, -0.1246, -0.0182), 1e-3));
+		assert!(approx(red.convert::<color::DisplayP3>().get(), (0.9175, 0.2003, 0.1386), 1e-3));
+
+		// the transfer functions round trip
+		for i in 0..=100 {
+			let x = i as f32 / 100.0;
+			let c = color::Rec2020::new(x, -x, x * 0.5);
+			assert!(approx(c.to_linear().to_rec2020().get(), c.get(), 1e-5), "{c:?}");
+			let c = color::AdobeRgb::new(x, -x, x * 0.5);
+			assert!(approx(c.to_linear().to_adobe_rgb().get(), c.get(), 1e-5), "{c:?}");
+		}
+	}
+
+	#[cfg(feature = "rng")]
+	#[test]
+	fn test_round_trip_xyz() {
+		use color::ColorSpace;
+		use crate::rng::XorShift64;
+
+		fn check<C: ColorSpace + std::fmt::Debug>(c: color::Srgb) {
+			let other: C = c.convert();
+			let back: color::Srgb = other.convert();
+			assert!(approx(back.get(), c.get(), 1e-3), "{c:?} -> {other:?} -> {back:?}");
+		}
+
+		let mut rng = XorShift64::new(0x5e7);
+
+		for _ in 0..1000 {
+			let c = color::Srgb::new(rng.nextf() as f32, rng.nextf() as f32, rng.nextf() as f32);
+			check::<color::Xyz>(c);
+			check::<color::XyzD50>(c);
+			check::<color::Lab>(c);
+			check::<color::Lch>(c);
+			check::<color::DisplayP3>(c);
+			check::<color::LinearDisplayP3>(c);
+			check::<color::Rec2020>(c);
+			check::<color::LinearRec2020>(c);
+			check::<color::AdobeRgb>(c);
+			check::<color::LinearAdobeRgb>(c);
+			check::<color::OkLch>(c);
+			check::<color::Hwb>(c);
+
+			let lab: color::Lab = c.convert();
+			assert!(approx(color::Lab::from(color::Lch::from(lab)).get(), lab.get(), 1e-3));
+			assert!(approx(color::Xyz::from(color::XyzD50::from(c.to_xyz())).get(), c.to_xyz().get(), 1e-5));
+		}
+	}
+
+	#[test]
+	fn test_gamut() {
+		use color::{ColorSpace, Gamut, GamutMapping};
+
+		assert!(color::Srgb::new(1.0, 0.0, 0.5).in_gamut());
+		assert!(!color::Srgb::new(1.1, 0.0, 0.5).in_gamut());
+		assert!(color::Srgb::new(1.0, 1.0, 1.0).convert::<color::DisplayP3>().in_gamut());
+		assert_eq!(color::Srgb::new(1.1, -0.2, 0.5).clip().get(), (1.0, 0.0, 0.5));
+
+		// in gamut colors aren't changed
+		let c = color::Srgb::new(0.2, 0.5, 0.8);
+		let mapped: color::Srgb = c.to_oklab().to_gamut(GamutMapping::Perceptual);
+		assert!(approx(mapped.get(), c.get(), 1e-4));
+
+		// too bright or too dark becomes white or black
+		let white: color::Srgb = color::OkLch::new(1.2, 0.2, 100.0).to_gamut(GamutMapping::Perceptual);
+		assert!(approx(white.get(), (1.0, 1.0, 1.0), 1e-4));
+		let black: color::Srgb = color::OkLch::new(-0.1, 0.2, 100.0).to_gamut(GamutMapping::Perceptual);
+		assert!(approx(black.get(), (0.0, 0.0, 0.0), 1e-4));
+
+		// mapping keeps hue and lightness much better than clipping
+		let green: color::OkLch = color::DisplayP3::new(0.0, 1.0, 0.0).convert();
+		let mapped: color::Srgb = green.to_gamut(GamutMapping::Perceptual);
+		let clipped: color::Srgb = green.to_gamut(GamutMapping::Clip);
+		assert!(mapped.in_gamut() && clipped.in_gamut());
+		let mapped: color::OkLch = mapped.convert();
+		let clipped: color::OkLch = clipped.convert();
+		assert!((mapped.l - green.l).abs() < 0.02, "{mapped:?} {green:?}");
+		assert!((mapped.h - green.h).abs() < 3.0, "{mapped:?} {green:?}");
+		assert!(mapped.c < green.c);
+		assert!((mapped.h - green.h).abs() < (clipped.h - green.h).abs());
+	}
+
+	#[cfg(feature = "rng")]
+	#[test]
+	fn test_gamut_random() {
+		use color::{Gamut, GamutMapping};
+		use crate::rng::XorShift64;
+
+		let mut rng = XorShift64::new(0x9a);
+
+		for _ in 0..500 {
+			let c = color::OkLch::new(rng.range(0.0, 1.0) as f32, rng.range(0.0, 0.4) as f32, rng.range(0.0, 360.0) as f32);
+			for mapping in [GamutMapping::Clip, GamutMapping::Perceptual] {
+				assert!(c.to_gamut::<color::Srgb>(mapping).in_gamut(), "{c:?}");
+				assert!(c.to_gamut::<color::DisplayP3>(mapping).in_gamut(), "{c:?}");
+				assert!(c.to_gamut::<color::LinearRec2020>(mapping).in_gamut(), "{c:?}");
+			}
+		}
+	}
+
+	#[test]
+	fn test_alpha() {
+		let c = color::RGBA::from_hex_str("#fff0").expect("parse failed");
+		assert_eq!(c.get(), (1.0, 1.0, 1.0, 0.0));
+		let c = color::RGBA::from_hex_str("ff00ff80").expect("parse failed");
+		assert_eq!(c.get(), (1.0, 0.0, 1.0, 128.0 / 255.0));
+		let c = color::RGBA::from_hex_str("#123").expect("parse failed");
+		assert_eq!(c.a, 1.0);
+
+		let c = color::RGBA::new(0.5, 1.0, 0.25, 0.5);
+		assert_eq!(c.premultiply().get(), (0.25, 0.5, 0.125, 0.5));
+		assert_eq!(c.premultiply().unpremultiply(), c);
+		assert_eq!(color::RGBA::new(0.5, 1.0, 0.25, 0.0).premultiply().unpremultiply(), color::RGBA::default());
+
+		let lab = c.to_oklaba();
+		assert_eq!(lab.alpha, 0.5);
+		let back = lab.to_rgba();
+		assert!((back.r - c.r).abs() < 1e-4 && (back.g - c.g).abs() < 1e-4 && (back.b - c.b).abs() < 1e-4);
+		assert_eq!(back.a, 0.5);
+	}
+
+	#[test]
+	fn test_composite() {
+		let red = color::RGBA::new(1.0, 0.0, 0.0, 1.0);
+		let blue = color::RGBA::new(0.0, 0.0, 1.0, 0.5);
+		let clear = color::RGBA::default();
+
+		assert_eq!(blue.over(red).get(), (0.5, 0.0, 0.5, 1.0));
+		assert_eq!(red.over(blue), red);
+		assert_eq!(clear.over(blue), blue);
+		assert_eq!(blue.over(clear), blue);
+		assert_eq!(clear.over(clear), clear);
+		let half = color::RGBA::new(1.0, 0.0, 0.0, 0.5);
+		assert_eq!(half.over(half).a, 0.75);
+
+		// transparent colors don't tint the interpolation
+		let c = red.lerp(clear, 0.5);
+		assert_eq!(c.get(), (1.0, 0.0, 0.0, 0.5));
+		assert_eq!(red.lerp(blue, 0.0), red);
+		assert_eq!(red.lerp(blue, 1.0), blue);
+
+		let c = red.to_oklaba().lerp(clear.to_oklaba(), 0.5);
+		assert_eq!(c.oklab(), red.to_oklaba().oklab());
+		assert_eq!(c.alpha, 0.5);
 	}
 }
 
@@ -0,0 +1,588 @@
+/*!
+curves and splines through [`Point`]s.
+
+- [`QuadBezier`] and [`CubicBezier`]
+- [`Hermite`] - a cubic segment given by its endpoints and tangents
+- [`CatmullRom`] - a spline passing through its control points
+- [`BSpline`] - a clamped uniform b-spline, of any degree
+- [`ArcLength`] - a table for moving along a curve at constant speed
+
+every curve implements [`Curve`], and is parameterized over `0..=1`.
+
+```
+# use lykoi_data::{point, curve::{Curve, CubicBezier, ArcLength}};
+let curve = CubicBezier::new(point![0.0f64, 0.0], point![1.0, 2.0], point![3.0, 2.0], point![4.0, 0.0]);
+let table = ArcLength::new(&curve, 64);
+
+// halfway along the curve, by distance
+let t = table.param(table.length() / 2.0);
+assert!((curve.position(t)[0] - 2.0).abs() < 1e-6);
+```
+*/
+
+use std::fmt::Debug;
+
+use crate::point::Point;
+
+/// a parametric curve, defined for `t` in `0..=1`.
+pub trait Curve<T, const N: usize>
+where T: Copy + Debug {
+	/// the point on the curve at `t`.
+	fn position(&self, t: T) -> Point<T, N>;
+	/// the first derivative (velocity) of the curve at `t`.
+	fn derivative(&self, t: T) -> Point<T, N>;
+}
+
+/// a quadratic bézier curve, from `p0` to `p2`, pulled towards `p1`.
+#[derive(Debug, Clone, Copy)]
+pub struct QuadBezier<T, const N: usize>
+where T: Copy + Debug {
+	pub p0: Point<T, N>,
+	pub p1: Point<T, N>,
+	pub p2: Point<T, N>,
+}
+impl<T, const N: usize> QuadBezier<T, N>
+where T: Copy + Debug + num_traits::Float {
+	#[inline]
+	pub fn new(p0: Point<T, N>, p1: Point<T, N>, p2: Point<T, N>) -> Self {
+		Self {
+			p0,
+			p1,
+			p2,
+		}
+	}
+
+	/// the second derivative, which is constant for a quadratic curve.
+	#[inline]
+	pub fn second_derivative(&self) -> Point<T, N> {
+		(self.p2 - self.p1 * (T::one() + T::one()) + self.p0) * (T::one() + T::one())
+	}
+
+	/// converts into the equivalent cubic curve.
+	pub fn to_cubic(&self) -> CubicBezier<T, N> {
+		let third = T::one() / (T::one() + T::one() + T::one());
+		let two_thirds = third + third;
+		CubicBezier::new(
+			self.p0,
+			self.p0 + (self.p1 - self.p0) * two_thirds,
+			self.p2 + (self.p1 - self.p2) * two_thirds,
+			self.p2,
+		)
+	}
+}
+impl<T, const N: usize> Curve<T, N> for QuadBezier<T, N>
+where T: Copy + Debug + num_traits::Float {
+	fn position(&self, t: T) -> Point<T, N> {
+		let u = T::one() - t;
+		self.p0 * (u * u) + self.p1 * ((T::one() + T::one()) * u * t) + self.p2 * (t * t)
+	}
+	fn derivative(&self, t: T) -> Point<T, N> {
+		let two = T::one() + T::one();
+		(self.p1 - self.p0) * (two * (T::one() - t)) + (self.p2 - self.p1) * (two * t)
+	}
+}
+
+/// a cubic bézier curve, from `p0` to `p3`, with control points `p1` and `p2`.
+#[derive(Debug, Clone, Copy)]
+pub struct CubicBezier<T, const N: usize>
+where T: Copy + Debug {
+	pub p0: Point<T, N>,
+	pub p1: Point<T, N>,
+	pub p2: Point<T, N>,
+	pub p3: Point<T, N>,
+}
+impl<T, const N: usize> CubicBezier<T, N>
+where T: Copy + Debug + num_traits::Float {
+	#[inline]
+	pub fn new(p0: Point<T, N>, p1: Point<T, N>, p2: Point<T, N>, p3: Point<T, N>) -> Self {
+		Self {
+			p0,
+			p1,
+			p2,
+			p3,
+		}
+	}
+
+	pub fn second_derivative(&self, t: T) -> Point<T, N> {
+		let six = (T::one() + T::one()) * (T::one() + T::one() + T::one());
+		let a = self.p2 - self.p1 * (T::one() + T::one()) + self.p0;
+		let b = self.p3 - self.p2 * (T::one() + T::one()) + self.p1;
+		(a * (T::one() - t) + b * t) * six
+	}
+
+	/// converts into the equivalent hermite segment.
+	pub fn to_hermite(&self) -> Hermite<T, N> {
+		let three = T::one() + T::one() + T::one();
+		Hermite::new(self.p0, (self.p1 - self.p0) * three, self.p3, (self.p3 - self.p2) * three)
+	}
+}
+impl<T, const N: usize> Curve<T, N> for CubicBezier<T, N>
+where T: Copy + Debug + num_traits::Float {
+	fn position(&self, t: T) -> Point<T, N> {
+		let three = T::one() + T::one() + T::one();
+		let u = T::one() - t;
+		self.p0 * (u * u * u)
+			+ self.p1 * (three * u * u * t)
+			+ self.p2 * (three * u * t * t)
+			+ self.p3 * (t * t * t)
+	}
+	fn derivative(&self, t: T) -> Point<T, N> {
+		let three = T::one() + T::one() + T::one();
+		let u = T::one() - t;
+		(self.p1 - self.p0) * (three * u * u)
+			+ (self.p2 - self.p1) * ((three + three) * u * t)
+			+ (self.p3 - self.p2) * (three * t * t)
+	}
+}
+
+/// a cubic hermite segment, from `p0` to `p1`, leaving with tangent `m0` and arriving with tangent `m1`.
+#[derive(Debug, Clone, Copy)]
+pub struct Hermite<T, const N: usize>
+where T: Copy + Debug {
+	pub p0: Point<T, N>,
+	pub m0: Point<T, N>,
+	pub p1: Point<T, N>,
+	pub m1: Point<T, N>,
+}
+impl<T, const N: usize> Hermite<T, N>
+where T: Copy + Debug + num_traits::Float {
+	#[inline]
+	pub fn new(p0: Point<T, N>, m0: Point<T, N>, p1: Point<T, N>, m1: Point<T, N>) -> Self {
+		Self {
+			p0,
+			m0,
+			p1,
+			m1,
+		}
+	}
+
+	/// converts into the equivalent cubic bézier curve.
+	pub fn to_bezier(&self) -> CubicBezier<T, N> {
+		let three = T::one() + T::one() + T::one();
+		CubicBezier::new(self.p0, self.p0 + self.m0 / three, self.p1 - self.m1 / three, self.p1)
+	}
+}
+impl<T, const N: usize> Curve<T, N> for Hermite<T, N>
+where T: Copy + Debug + num_traits::Float {
+	fn position(&self, t: T) -> Point<T, N> {
+		let two = T::one() + T::one();
+		let three = two + T::one();
+		let t2 = t * t;
+		let t3 = t2 * t;
+		self.p0 * (two * t3 - three * t2 + T::one())
+			+ self.m0 * (t3 - two * t2 + t)
+			+ self.p1 * (three * t2 - two * t3)
+			+ self.m1 * (t3 - t2)
+	}
+	fn derivative(&self, t: T) -> Point<T, N> {
+		let two = T::one() + T::one();
+		let three = two + T::one();
+		let six = three + three;
+		let t2 = t * t;
+		self.p0 * (six * t2 - six * t)
+			+ self.m0 * (three * t2 - (two + two) * t + T::one())
+			+ self.p1 * (six * t - six * t2)
+			+ self.m1 * (three * t2 - two * t)
+	}
+}
+
+/// how a [`CatmullRom`] spline spaces its knots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Parameterization {
+	/// evenly spaced knots. can overshoot and form loops when points are unevenly spaced.
+	Uniform,
+	/// knots spaced by the square root of the distance between points. never forms cusps or loops within a segment.
+	Centripetal,
+	/// knots spaced by the distance between points.
+	Chordal,
+}
+
+/**
+a catmull-rom spline, passing through every control point except the first and last,
+which only shape the ends of the curve.
+
+`t = 0` is at `points[1]`, and `t = 1` is at `points[len - 2]`.
+*/
+#[derive(Debug, Clone)]
+pub struct CatmullRom<T, const N: usize>
+where T: Copy + Debug {
+	points: Vec<Point<T, N>>,
+	kind: Parameterization,
+}
+impl<T, const N: usize> CatmullRom<T, N>
+where T: Copy + Debug + num_traits::Float {
+	/// returns `None` if there are less than 4 points.
+	pub fn new(points: Vec<Point<T, N>>, kind: Parameterization) -> Option<Self> {
+		if points.len() < 4 {
+			return None;
+		}
+		Some(Self {
+			points,
+			kind,
+		})
+	}
+
+	#[inline]
+	pub fn points(&self) -> &[Point<T, N>] {
+		&self.points
+	}
+	#[inline]
+	pub fn kind(&self) -> Parameterization {
+		self.kind
+	}
+	/// the number of segments, one less than the number of points the curve passes through.
+	#[inline]
+	pub fn segments(&self) -> usize {
+		self.points.len() - 3
+	}
+
+	/**
+	the `i`th segment, between `points[i + 1]` and `points[i + 2]`, as a hermite segment.
+
+	# panics
+	panics if `i >= self.segments()`.
+	*/
+	pub fn segment(&self, i: usize) -> Hermite<T, N> {
+		let [p0, p1, p2, p3] = [0, 1, 2, 3].map(|x| self.points[i + x]);
+
+		// knot intervals. coincident points would give a zero interval, so fall back to uniform spacing
+		let interval = |a: Point<T, N>, b: Point<T, N>| {
+			let d = match self.kind {
+				Parameterization::Uniform => T::one(),
+				Parameterization::Centripetal => a.distance(b).sqrt(),
+				Parameterization::Chordal => a.distance(b),
+			};
+			if d > T::epsilon() { d } else { T::one() }
+		};
+		let d0 = interval(p0, p1);
+		let d1 = interval(p1, p2);
+		let d2 = interval(p2, p3);
+
+		// tangents at p1 and p2 over the knot interval d1, from yuksel et al.
+		let m1 = ((p1 - p0) / d0 - (p2 - p0) / (d0 + d1) + (p2 - p1) / d1) * d1;
+		let m2 = ((p2 - p1) / d1 - (p3 - p1) / (d1 + d2) + (p3 - p2) / d2) * d1;
+
+		Hermite::new(p1, m1, p2, m2)
+	}
+
+	// maps global `t` to a segment and its local parameter
+	fn locate(&self, t: T) -> (usize, T) {
+		let count = self.segments();
+		let scaled = t.max(T::zero()).min(T::one()) * T::from(count).unwrap();
+		let i = scaled.floor().to_usize().unwrap_or(0).min(count - 1);
+		(i, scaled - T::from(i).unwrap())
+	}
+}
+impl<T, const N: usize> Curve<T, N> for CatmullRom<T, N>
+where T: Copy + Debug + num_traits::Float {
+	fn position(&self, t: T) -> Point<T, N> {
+		let (i, local) = self.locate(t);
+		self.segment(i).position(local)
+	}
+	fn derivative(&self, t: T) -> Point<T, N> {
+		let (i, local) = self.locate(t);
+		self.segment(i).derivative(local) * T::from(self.segments()).unwrap()
+	}
+}
+
+/**
+a clamped b-spline with uniformly spaced knots.
+
+the curve starts at the first control point and ends at the last, and is otherwise
+only pulled towards the control points. a degree of 1 gives the polyline through the points,
+and a degree of 3 gives the common cubic b-spline.
+*/
+#[derive(Debug, Clone)]
+pub struct BSpline<T, const N: usize>
+where T: Copy + Debug {
+	points: Vec<Point<T, N>>,
+	knots: Vec<T>,
+	degree: usize,
+}
+impl<T, const N: usize> BSpline<T, N>
+where T: Copy + Debug + num_traits::Float {
+	/// returns `None` if `degree` is zero, or there aren't more than `degree` points.
+	pub fn new(points: Vec<Point<T, N>>, degree: usize) -> Option<Self> {
+		if degree == 0 || points.len() <= degree {
+			return None;
+		}
+		let spans = points.len() - degree;
+		let knots = (0..points.len() + degree + 1)
+			.map(|i| {
+				let i = i.saturating_sub(degree).min(spans);
+				T::from(i).unwrap() / T::from(spans).unwrap()
+			})
+			.collect();
+		Some(Self {
+			points,
+			knots,
+			degree,
+		})
+	}
+
+	#[inline]
+	pub fn points(&self) -> &[Point<T, N>] {
+		&self.points
+	}
+	#[inline]
+	pub fn knots(&self) -> &[T] {
+		&self.knots
+	}
+	#[inline]
+	pub fn degree(&self) -> usize {
+		self.degree
+	}
+
+	// evaluates a spline of `degree` with control points `points` and knots `knots` using de boor's algorithm
+	fn de_boor(points: &[Point<T, N>], knots: &[T], degree: usize, t: T) -> Point<T, N> {
+		let t = t.max(T::zero()).min(T::one());
+		// the knot span containing t, with the last span closed at t = 1
+		let span = (degree..points.len())
+			.rev()
+			.find(|&i| knots[i] <= t && (knots[i] < knots[i + 1]))
+			.unwrap_or(degree);
+
+		let mut d: Vec<Point<T, N>> = (0..=degree).map(|j| points[span - degree + j]).collect();
+		for r in 1..=degree {
+			for j in (r..=degree).rev() {
+				let i = span - degree + j;
+				let denom = knots[i + degree + 1 - r] - knots[i];
+				let alpha = if denom > T::zero() { (t - knots[i]) / denom } else { T::zero() };
+				d[j] = d[j - 1].lerp(d[j], alpha);
+			}
+		}
+		d[degree]
+	}
+}
+impl<T, const N: usize> Curve<T, N> for BSpline<T, N>
+where T: Copy + Debug + num_traits::Float {
+	fn position(&self, t: T) -> Point<T, N> {
+		Self::de_boor(&self.points, &self.knots, self.degree, t)
+	}
+	/// the derivative is itself a b-spline, one degree lower, which is built on every call.
+	fn derivative(&self, t: T) -> Point<T, N> {
+		let p = self.degree;
+		let scale = T::from(p).unwrap();
+		let points: Vec<Point<T, N>> = self.points.windows(2).enumerate()
+			.map(|(i, w)| {
+				let denom = self.knots[i + p + 1] - self.knots[i + 1];
+				if denom > T::zero() { (w[1] - w[0]) * (scale / denom) } else { Point::default() }
+			})
+			.collect();
+		Self::de_boor(&points, &self.knots[1..self.knots.len() - 1], p - 1, t)
+	}
+}
+
+/**
+a table mapping distance along a curve to the curve's parameter, for moving along a curve at constant speed.
+
+the curve is approximated by `samples` straight segments, so more samples give a more accurate table.
+*/
+#[derive(Debug, Clone)]
+pub struct ArcLength<T>
+where T: Copy + Debug {
+	// cumulative length at evenly spaced parameters, starting at zero
+	lengths: Vec<T>,
+}
+impl<T> ArcLength<T>
+where T: Copy + Debug + num_traits::Float {
+	/// builds a table for `curve`. `samples` is clamped to at least 1.
+	pub fn new<const N: usize>(curve: &impl Curve<T, N>, samples: usize) -> Self {
+		let samples = samples.max(1);
+		let count = T::from(samples).unwrap();
+		let mut lengths = Vec::with_capacity(samples + 1);
+		lengths.push(T::zero());
+		let mut last = curve.position(T::zero());
+		let mut total = T::zero();
+		for i in 1..=samples {
+			let next = curve.position(T::from(i).unwrap() / count);
+			total = total + last.distance(next);
+			lengths.push(total);
+			last = next;
+		}
+		Self {
+			lengths,
+		}
+	}
+
+	/// the approximate total length of the curve.
+	#[inline]
+	pub fn length(&self) -> T {
+		*self.lengths.last().unwrap()
+	}
+
+	/// the parameter at `distance` along the curve. `distance` is clamped to the curve's length.
+	pub fn param(&self, distance: T) -> T {
+		let samples = T::from(self.lengths.len() - 1).unwrap();
+		let distance = distance.max(T::zero()).min(self.length());
+		let i = self.lengths.partition_point(|&x| x < distance);
+		if i == 0 {
+			return T::zero();
+		}
+		let (a, b) = (self.lengths[i - 1], self.lengths[i]);
+		let frac = if b > a { (distance - a) / (b - a) } else { T::zero() };
+		(T::from(i - 1).unwrap() + frac) / samples
+	}
+
+	/// the parameter at `fraction` of the way along the curve, by distance.
+	#[inline]
+	pub fn param_normalized(&self, fraction: T) -> T {
+		self.param(fraction * self.length())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use crate::point;
+	use super::*;
+
+	fn approx<const N: usize>(a: Point<f64, N>, b: Point<f64, N>) -> bool {
+		a.distance(b) < 1e-6
+	}
+
+	// checks the derivative against a central difference
+	fn check_derivative<const N: usize>(curve: &impl Curve<f64, N>) {
+		let h = 1e-6;
+		for i in 1..20 {
+			let t = i as f64 / 20.0;
+			let numeric = (curve.position(t + h) - curve.position(t - h)) / (2.0 * h);
+			assert!(numeric.distance(curve.derivative(t)) < 1e-4, "{t}: {numeric} != {}", curve.derivative(t));
+		}
+	}
+
+	#[test]
+	fn test_bezier() {
+		let quad = QuadBezier::new(point![0.0, 0.0], point![1.0, 2.0], point![2.0, 0.0]);
+		assert!(approx(quad.position(0.0), point![0.0, 0.0]));
+		assert!(approx(quad.position(0.5), point![1.0, 1.0]));
+		assert!(approx(quad.position(1.0), point![2.0, 0.0]));
+		assert!(approx(quad.derivative(0.0), point![2.0, 4.0]));
+		assert!(approx(quad.second_derivative(), point![0.0, -8.0]));
+		check_derivative(&quad);
+
+		let cubic = quad.to_cubic();
+		for i in 0..=10 {
+			let t = i as f64 / 10.0;
+			assert!(approx(quad.position(t), cubic.position(t)));
+		}
+
+		let cubic = CubicBezier::new(point![0.0, 0.0, 0.0], point![1.0, 2.0, 0.0], point![3.0, 2.0, 1.0], point![4.0, 0.0, 1.0]);
+		assert!(approx(cubic.position(0.0), cubic.p0));
+		assert!(approx(cubic.position(1.0), cubic.p3));
+		assert!(approx(cubic.derivative(0.0), (cubic.p1 - cubic.p0) * 3.0));
+		check_derivative(&cubic);
+
+		let h = 1e-5;
+		let numeric = (cubic.derivative(0.3 + h) - cubic.derivative(0.3 - h)) / (2.0 * h);
+		assert!(numeric.distance(cubic.second_derivative(0.3)) < 1e-4);
+	}
+
+	#[test]
+	fn test_hermite() {
+		let hermite = Hermite::new(point![0.0, 0.0], point![3.0, 0.0], point![2.0, 2.0], point![0.0, 3.0]);
+		assert!(approx(hermite.position(0.0), hermite.p0));
+		assert!(approx(hermite.position(1.0), hermite.p1));
+		assert!(approx(hermite.derivative(0.0), hermite.m0));
+		assert!(approx(hermite.derivative(1.0), hermite.m1));
+		check_derivative(&hermite);
+
+		let bezier = hermite.to_bezier();
+		for i in 0..=10 {
+			let t = i as f64 / 10.0;
+			assert!(approx(hermite.position(t), bezier.position(t)));
+		}
+		let back = bezier.to_hermite();
+		assert!(approx(back.m0, hermite.m0) && approx(back.m1, hermite.m1));
+	}
+
+	#[test]
+	fn test_catmull_rom() {
+		let points = vec![
+			point![0.0, 0.0],
+			point![1.0, 1.0],
+			point![1.5, 1.0],
+			point![4.0, 0.0],
+			point![4.0, 3.0],
+			point![5.0, 3.0],
+		];
+		assert!(CatmullRom::new(points[..3].to_vec(), Parameterization::Uniform).is_none());
+
+		for kind in [Parameterization::Uniform, Parameterization::Centripetal, Parameterization::Chordal] {
+			let spline = CatmullRom::new(points.clone(), kind).unwrap();
+			assert_eq!(spline.segments(), 3);
+
+			// passes through every interior point
+			for i in 0..=3 {
+				assert!(approx(spline.position(i as f64 / 3.0), points[i + 1]));
+			}
+			check_derivative(&spline);
+
+			// segments join smoothly
+			for i in 0..2 {
+				assert!(approx(spline.segment(i).derivative(1.0).normalize(), spline.segment(i + 1).derivative(0.0).normalize()));
+			}
+		}
+
+		// uniform catmull-rom uses half the difference of the neighbors as the tangent
+		let spline = CatmullRom::new(points.clone(), Parameterization::Uniform).unwrap();
+		assert!(approx(spline.segment(0).m0, (points[2] - points[0]) * 0.5));
+
+		// coincident points don't produce nan
+		let spline = CatmullRom::new(vec![point![0.0, 0.0], point![0.0, 0.0], point![1.0, 0.0], point![1.0, 0.0]], Parameterization::Centripetal).unwrap();
+		assert!(approx(spline.position(0.5), point![0.5, 0.0]));
+	}
+
+	#[test]
+	fn test_bspline() {
+		let points = vec![point![0.0, 0.0], point![1.0, 2.0], point![3.0, 2.0], point![4.0, 0.0], point![6.0, 1.0]];
+		assert!(BSpline::new(points.clone(), 0).is_none());
+		assert!(BSpline::new(points.clone(), 5).is_none());
+
+		// degree 1 is the polyline through the points
+		let linear = BSpline::new(points.clone(), 1).unwrap();
+		for (i, &p) in points.iter().enumerate() {
+			assert!(approx(linear.position(i as f64 / 4.0), p));
+		}
+		assert!(approx(linear.position(0.125), point![0.5, 1.0]));
+		assert!(approx(linear.derivative(0.125), point![4.0, 8.0]));
+
+		for degree in 2..=4 {
+			let spline = BSpline::new(points.clone(), degree).unwrap();
+			assert_eq!(spline.knots().len(), points.len() + degree + 1);
+			assert!(approx(spline.position(0.0), points[0]));
+			assert!(approx(spline.position(1.0), points[4]));
+			check_derivative(&spline);
+		}
+
+		// a single span of degree 3 is a cubic bézier
+		let spline = BSpline::new(points[..4].to_vec(), 3).unwrap();
+		let bezier = CubicBezier::new(points[0], points[1], points[2], points[3]);
+		for i in 0..=10 {
+			let t = i as f64 / 10.0;
+			assert!(approx(spline.position(t), bezier.position(t)));
+			assert!(approx(spline.derivative(t), bezier.derivative(t)));
+		}
+	}
+
+	#[test]
+	fn test_arc_length() {
+		// a straight line with uneven control points doesn't move at constant speed
+		let curve = CubicBezier::new(point![0.0, 0.0], point![9.0, 0.0], point![9.5, 0.0], point![10.0, 0.0]);
+		let table = ArcLength::new(&curve, 256);
+		assert!((table.length() - 10.0f64).abs() < 1e-9);
+
+		assert_eq!(table.param(-1.0), 0.0);
+		assert_eq!(table.param(20.0), 1.0);
+		for i in 0..=10 {
+			let t = table.param(i as f64);
+			assert!((curve.position(t)[0] - i as f64).abs() < 1e-2);
+		}
+		assert_eq!(table.param_normalized(0.5), table.param(5.0));
+
+		// a quarter circle, approximately
+		let k = 0.5522847498;
+		let curve = CubicBezier::new(point![1.0, 0.0], point![1.0, k], point![k, 1.0], point![0.0, 1.0]);
+		let table = ArcLength::new(&curve, 256);
+		assert!((table.length() - std::f64::consts::FRAC_PI_2).abs() < 1e-3);
+	}
+}
@@ -6,23 +6,59 @@ constructed into an ordered `Vec<T>` such that any point that had an edge coming
 it will be placed *after* the point the edge started from.
 
 see the [documentation]([Dag]) for more info.
+
+if your points are better identified by name (such as passes read from a config file),
+[`KeyedDag`] wraps a [`Dag`] and lets edges be declared by key instead.
 */
 
-#[derive(Debug)]
+use std::{collections::HashMap, hash::Hash};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DagError {
 	Incomplete,
+	Missing,
+	OutOfRange,
+	Order,
+	Malformed,
+	Version,
+	TooLarge,
 }
 impl std::error::Error for DagError {}
 impl std::fmt::Display for DagError {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		write!(f, "{}", match self {
 			DagError::Incomplete => "Graph was incomplete",
+			DagError::Missing => "Graph referenced a missing key",
+			DagError::OutOfRange => "Graph had an edge to a point that doesn't exist",
+			DagError::Order => "Order didn't match the graph",
+			DagError::Malformed => "Encoded graph was malformed",
+			DagError::Version => "Encoded graph had an unsupported version",
+			DagError::TooLarge => "Graph was too large to encode",
 		})
 	}
 }
 
 /// represents a point. obtained from [`Dag::point()`], to be used with [`Dag::depend()`].
-pub struct Index(usize);
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Index(pub(crate) usize);
+
+/**
+maps the [`Index`]es of one [`Dag`] onto another, which only kept some of its points.
+
+obtained from [`Dag::subgraph()`] and [`Dag::prune_to()`].
+*/
+#[derive(Debug, Clone)]
+pub struct Remap {
+	map: Vec<Option<usize>>,
+}
+impl Remap {
+	/// returns the new [`Index`] of `old`, or `None` if that point was removed.
+	pub fn get(&self, old: &Index) -> Option<Index> {
+		self.map.get(old.0).copied().flatten().map(Index)
+	}
+}
 
 /**
 a "[directed acyclic graph](https://en.wikipedia.org/wiki/Directed_acyclic_graph)".
@@ -52,11 +88,33 @@ this is useful for representing a complex chain of dependencies,
 such as for complex shader passes!
 */
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "DagRaw<T>"))]
 pub struct Dag<T> {
 	// todo: can we remove Vec?
 	points: Vec<T>,
 	edges: Vec<(usize, usize)>,
 }
+
+// deserialized first, so edges can be validated before becoming a `Dag`
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct DagRaw<T> {
+	points: Vec<T>,
+	edges: Vec<(usize, usize)>,
+}
+#[cfg(feature = "serde")]
+impl<T> TryFrom<DagRaw<T>> for Dag<T> {
+	type Error = DagError;
+	fn try_from(value: DagRaw<T>) -> Result<Self, Self::Error> {
+		let dag = Dag {
+			points: value.points,
+			edges: value.edges,
+		};
+		dag.check_edges()?;
+		Ok(dag)
+	}
+}
 impl<T: Clone> Dag<T> {
 	/// constructs a new `Dag<T>`.
 	pub fn new() -> Self {
@@ -106,6 +164,113 @@ impl<T: Clone> Dag<T> {
 		self.edges.push((value.0, needs.0));
 	}
 
+	/**
+	creates a new `Dag<T>` containing only the points `predicate` returns `true` for,
+	and the edges between them.
+
+	the returned [`Remap`] translates [`Index`]es of `self` into the new graph.
+
+	```
+	# use lykoi_data::dag::Dag;
+	let mut dag = Dag::new();
+
+	let a = dag.point(1);
+	let b = dag.point(2);
+	let c = dag.point(3);
+
+	dag.depend(&a, &b);
+	dag.depend(&b, &c);
+
+	let (odd, remap) = dag.subgraph(|x| x % 2 == 1);
+
+	assert!(remap.get(&a).is_some());
+	assert!(remap.get(&b).is_none());
+	assert_eq!(odd.build().unwrap().len(), 2);
+	```
+	*/
+	pub fn subgraph(&self, predicate: impl Fn(&T) -> bool) -> (Dag<T>, Remap) {
+		let keep = self.points.iter().map(predicate).collect::<Vec<_>>();
+		self.retain(&keep)
+	}
+
+	/**
+	removes every point that isn't in `outputs`, or isn't (transitively) needed by one.
+
+	this is the "dead pass culling" step of a frame graph: only points that contribute
+	to the requested outputs survive. the returned [`Remap`] translates the old [`Index`]es
+	into the pruned graph.
+
+	returns [`DagError::OutOfRange`] if an output or edge refers to a point that doesn't exist,
+	leaving the graph unchanged.
+
+	```
+	# use lykoi_data::dag::Dag;
+	let mut dag = Dag::new();
+
+	let hdr = dag.point("hdr");
+	let bloom = dag.point("bloom");
+	let debug = dag.point("debug");
+	let output = dag.point("output");
+
+	dag.depend(&bloom, &hdr);
+	dag.depend(&output, &bloom);
+	dag.depend(&debug, &hdr);
+
+	let remap = dag.prune_to(&[output]).unwrap();
+
+	assert!(remap.get(&debug).is_none());
+	assert_eq!(dag.build().unwrap(), vec!["hdr", "bloom", "output"]);
+	```
+	*/
+	pub fn prune_to(&mut self, outputs: &[Index]) -> Result<Remap, DagError> {
+		let len = self.points.len();
+		if outputs.iter().any(|x| x.0 >= len) {
+			return Err(DagError::OutOfRange);
+		}
+		self.check_edges()?;
+
+		let mut needs = vec![Vec::new(); len];
+		for &(value, need) in &self.edges {
+			needs[value].push(need);
+		}
+
+		let mut keep = vec![false; len];
+		let mut stack = outputs.iter().map(|x| x.0).collect::<Vec<_>>();
+
+		while let Some(i) = stack.pop() {
+			if keep[i] {
+				continue;
+			}
+			keep[i] = true;
+			stack.extend(needs[i].iter().filter(|&&x| !keep[x]));
+		}
+
+		let (dag, remap) = self.retain(&keep);
+		*self = dag;
+		Ok(remap)
+	}
+
+	fn retain(&self, keep: &[bool]) -> (Dag<T>, Remap) {
+		let mut points = Vec::new();
+		let mut map = Vec::with_capacity(self.points.len());
+
+		for (point, &k) in self.points.iter().zip(keep) {
+			if k {
+				map.push(Some(points.len()));
+				points.push(point.clone());
+			} else {
+				map.push(None);
+			}
+		}
+
+		let edges = self.edges
+			.iter()
+			.filter_map(|&(value, needs)| Some((map[value]?, map[needs]?)))
+			.collect();
+
+		(Dag { points, edges }, Remap { map })
+	}
+
 	/**
 	consumes `self`, and attempts to build a `Vec<T>` sorted such that any
 	point with dependencies appears after those dependencies.
@@ -140,6 +305,17 @@ impl<T: Clone> Dag<T> {
 	```
 	*/
 	pub fn build(self) -> Result<Vec<T>, DagError> {
+		let order = self.order()?;
+		Ok(order.into_iter().map(|x| self.points[x.0].clone()).collect())
+	}
+
+	/**
+	computes the order [`Self::build()`] would produce, as [`Index`]es, without consuming `self`.
+
+	`Err` is produced if the graph is incomplete, or two points in the
+	graph have a cyclic dependency.
+	*/
+	pub fn order(&self) -> Result<Vec<Index>, DagError> {
 		let mut counts = vec![Some(0u32); self.points.len()];
 
 		let mut queue = Vec::new();
@@ -186,7 +362,193 @@ impl<T: Clone> Dag<T> {
 			return Err(DagError::Incomplete);
 		}
 
-		Ok(result.into_iter().map(|x| self.points[x].clone()).collect())
+		Ok(result.into_iter().map(Index).collect())
+	}
+
+	/**
+	checks that `order` is a valid order for this graph: every point appears exactly once,
+	and every point appears after the points it depends on.
+
+	useful for validating an order that was computed earlier, such as one loaded from disk.
+	*/
+	pub fn check_order(&self, order: &[Index]) -> Result<(), DagError> {
+		if order.len() != self.points.len() {
+			return Err(DagError::Order);
+		}
+		let mut position = vec![None; self.points.len()];
+		for (i, x) in order.iter().enumerate() {
+			match position.get_mut(x.0) {
+				Some(p @ None) => *p = Some(i),
+				_ => return Err(DagError::Order),
+			}
+		}
+		for &(value, needs) in &self.edges {
+			if position[needs] >= position[value] {
+				return Err(DagError::Order);
+			}
+		}
+		Ok(())
+	}
+
+	/// returns the value of a point.
+	pub fn get(&self, index: &Index) -> Option<&T> {
+		self.points.get(index.0)
+	}
+
+	/**
+	encodes the graph into a compact binary format, to be loaded again with [`Self::decode()`].
+
+	`point` is called to append each point's bytes to the buffer. if the graph can be
+	built, its [`Self::order()`] is stored alongside it, so it doesn't have to be recomputed.
+
+	the format is little endian, and laid out as:
+	- the magic bytes `LDAG`, followed by a `u8` version
+	- a `u32` point count, then each point as a `u32` byte length followed by its bytes
+	- a `u32` edge count, then each edge as two `u32` point indices
+	- a `u8` flag, which if `1` is followed by the order as one `u32` point index per point
+
+	returns [`DagError::TooLarge`] if a count, length or index doesn't fit in a `u32`.
+	*/
+	pub fn encode(&self, mut point: impl FnMut(&T, &mut Vec<u8>)) -> Result<Vec<u8>, DagError> {
+		let mut out = Vec::new();
+		out.extend_from_slice(&ENCODE_MAGIC);
+		out.push(ENCODE_VERSION);
+
+		write_u32(&mut out, self.points.len())?;
+		let mut buf = Vec::new();
+		for x in &self.points {
+			buf.clear();
+			point(x, &mut buf);
+			write_u32(&mut out, buf.len())?;
+			out.extend_from_slice(&buf);
+		}
+
+		write_u32(&mut out, self.edges.len())?;
+		for &(value, needs) in &self.edges {
+			write_u32(&mut out, value)?;
+			write_u32(&mut out, needs)?;
+		}
+
+		match self.order() {
+			Ok(order) => {
+				out.push(1);
+				for x in order {
+					write_u32(&mut out, x.0)?;
+				}
+			},
+			Err(_) => out.push(0),
+		}
+
+		Ok(out)
+	}
+
+	/**
+	decodes a graph produced by [`Self::encode()`], along with its stored order, if any.
+
+	`point` is called with the bytes of each point, and returns `None` if they're invalid.
+
+	the input is validated: `Err` is produced if it's malformed or of an unknown version,
+	if an edge refers to a point that doesn't exist, or if the stored order doesn't
+	match the graph.
+
+	```
+	# use lykoi_data::dag::Dag;
+	let mut dag = Dag::new();
+
+	let a = dag.point(1u8);
+	let b = dag.point(2u8);
+	dag.depend(&a, &b);
+
+	let bytes = dag.encode(|x, out| out.push(*x)).unwrap();
+
+	let (dag, order) = Dag::decode(&bytes, |x| x.first().copied()).unwrap();
+	let order = order.unwrap();
+
+	assert_eq!(dag.get(&order[0]), Some(&2));
+	assert_eq!(dag.get(&order[1]), Some(&1));
+	```
+	*/
+	pub fn decode(bytes: &[u8], mut point: impl FnMut(&[u8]) -> Option<T>) -> Result<(Self, Option<Vec<Index>>), DagError> {
+		let mut reader = Reader(bytes);
+
+		if reader.take(ENCODE_MAGIC.len())? != ENCODE_MAGIC {
+			return Err(DagError::Malformed);
+		}
+		if reader.take(1)?[0] != ENCODE_VERSION {
+			return Err(DagError::Version);
+		}
+
+		let count = reader.u32()? as usize;
+		// don't trust `count` for preallocation, each point is at least 4 bytes
+		let mut points = Vec::with_capacity(count.min(reader.0.len() / 4));
+		for _ in 0..count {
+			let len = reader.u32()? as usize;
+			points.push(point(reader.take(len)?).ok_or(DagError::Malformed)?);
+		}
+
+		let count = reader.u32()? as usize;
+		let mut edges = Vec::with_capacity(count.min(reader.0.len() / 8));
+		for _ in 0..count {
+			edges.push((reader.u32()? as usize, reader.u32()? as usize));
+		}
+
+		let dag = Self { points, edges };
+		dag.check_edges()?;
+
+		let order = match reader.take(1)?[0] {
+			0 => None,
+			1 => {
+				let order = (0..dag.points.len())
+					.map(|_| reader.u32().map(|x| Index(x as usize)))
+					.collect::<Result<Vec<_>, _>>()?;
+				dag.check_order(&order)?;
+				Some(order)
+			},
+			_ => return Err(DagError::Malformed),
+		};
+
+		if !reader.0.is_empty() {
+			return Err(DagError::Malformed);
+		}
+
+		Ok((dag, order))
+	}
+}
+
+const ENCODE_MAGIC: [u8; 4] = *b"LDAG";
+const ENCODE_VERSION: u8 = 1;
+
+fn write_u32(out: &mut Vec<u8>, x: usize) -> Result<(), DagError> {
+	let x = u32::try_from(x).map_err(|_| DagError::TooLarge)?;
+	out.extend_from_slice(&x.to_le_bytes());
+	Ok(())
+}
+
+struct Reader<'a>(&'a [u8]);
+impl<'a> Reader<'a> {
+	fn take(&mut self, len: usize) -> Result<&'a [u8], DagError> {
+		if self.0.len() < len {
+			return Err(DagError::Malformed);
+		}
+		let (a, b) = self.0.split_at(len);
+		self.0 = b;
+		Ok(a)
+	}
+
+	fn u32(&mut self) -> Result<u32, DagError> {
+		let x = self.take(4)?;
+		Ok(u32::from_le_bytes([x[0], x[1], x[2], x[3]]))
+	}
+}
+
+impl<T> Dag<T> {
+	/// checks that every edge refers to a point that exists.
+	fn check_edges(&self) -> Result<(), DagError> {
+		let len = self.points.len();
+		if self.edges.iter().any(|&(value, needs)| value >= len || needs >= len) {
+			return Err(DagError::OutOfRange);
+		}
+		Ok(())
 	}
 }
 
@@ -196,6 +558,122 @@ impl<T: Clone> Default for Dag<T> {
 	}
 }
 
+/**
+a [`Dag`] whose points are identified by a key `K`, rather than by [`Index`].
+
+edges are declared with [`Self::depend_keys()`], and are only resolved when the
+graph is built. this means a dependency can be declared before the point it refers
+to has been added. any key that is still missing by then is reported as
+[`DagError::Missing`].
+
+```
+# use lykoi_data::dag::KeyedDag;
+let mut dag = KeyedDag::new();
+
+// "bloom" can depend on "hdr" before "hdr" exists
+dag.depend_keys("bloom", "hdr");
+
+dag.point("bloom", 1);
+dag.point("hdr", 0);
+
+let order = dag.build().unwrap();
+
+assert_eq!(order, vec![0, 1]);
+```
+*/
+#[derive(Debug, Clone)]
+pub struct KeyedDag<K, T> {
+	keys: HashMap<K, usize>,
+	points: Vec<T>,
+	edges: Vec<(K, K)>,
+}
+impl<K: Hash + Eq, T: Clone> KeyedDag<K, T> {
+	/// constructs a new `KeyedDag<K, T>`.
+	pub fn new() -> Self {
+		Self {
+			keys: HashMap::new(),
+			points: Vec::new(),
+			edges: Vec::new(),
+		}
+	}
+
+	/**
+	creates a new point identified by `key`.
+
+	if a point with this key already exists, its value is replaced, and the old
+	value is returned.
+	*/
+	pub fn point(&mut self, key: K, value: T) -> Option<T> {
+		if let Some(&i) = self.keys.get(&key) {
+			return Some(std::mem::replace(&mut self.points[i], value));
+		}
+		self.points.push(value);
+		self.keys.insert(key, self.points.len() - 1);
+		None
+	}
+
+	/// returns `true` if a point with this key has been added.
+	pub fn contains(&self, key: &K) -> bool {
+		self.keys.contains_key(key)
+	}
+
+	/**
+	sets up an edge from `value` to `needs`. ie: "value X needs Y"
+
+	neither key has to exist yet, they are only looked up once the graph is built.
+	*/
+	pub fn depend_keys(&mut self, value: K, needs: K) {
+		self.edges.push((value, needs));
+	}
+
+	/**
+	returns every key referred to by [`Self::depend_keys()`] that doesn't have a point.
+
+	a key may appear more than once if it was referred to by more than one edge.
+	*/
+	pub fn missing(&self) -> impl Iterator<Item = &K> {
+		self.edges
+			.iter()
+			.flat_map(|(a, b)| [a, b])
+			.filter(|k| !self.keys.contains_key(k))
+	}
+
+	/**
+	consumes `self`, resolving every key into a plain [`Dag`].
+
+	`Err` is produced if any edge refers to a key that was never added.
+	*/
+	pub fn into_dag(self) -> Result<Dag<T>, DagError> {
+		let mut edges = Vec::with_capacity(self.edges.len());
+		for (value, needs) in &self.edges {
+			let (Some(&value), Some(&needs)) = (self.keys.get(value), self.keys.get(needs)) else {
+				return Err(DagError::Missing);
+			};
+			edges.push((value, needs));
+		}
+		Ok(Dag {
+			points: self.points,
+			edges,
+		})
+	}
+
+	/**
+	consumes `self`, and attempts to build a `Vec<T>`, just like [`Dag::build()`].
+
+	`Err` is produced if any edge refers to a key that was never added, or if
+	the graph is incomplete.
+	*/
+	pub fn build(self) -> Result<Vec<T>, DagError> {
+		self.into_dag()?.build()
+	}
+}
+
+impl<K: Hash + Eq, T: Clone> Default for KeyedDag<K, T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 
 #[cfg(test)]
 mod test {
@@ -235,6 +713,161 @@ mod test {
 	
 		assert!(&order.is_err());
 	}
+
+	#[test]
+	fn test_prune() {
+		let mut dag = super::Dag::new();
+
+		let a = dag.point(10);
+		let b = dag.point(20);
+		let c = dag.point(30);
+		let d = dag.point(40);
+		let e = dag.point(50);
+
+		dag.depend(&b, &a);
+		dag.depend(&c, &b);
+		dag.depend(&d, &a);
+		dag.depend(&e, &d);
+
+		let remap = dag.prune_to(&[c, d]).unwrap();
+
+		assert!(remap.get(&e).is_none());
+		assert_eq!(remap.get(&d), Some(super::Index(3)));
+
+		let order = dag.clone().build().unwrap();
+
+		assert_eq!(order.len(), 4);
+		assert_eq!(order[0], 10);
+		assert!(order.iter().position(|&x| x == 20) < order.iter().position(|&x| x == 30));
+
+		// indices from another, larger graph
+		let mut other = super::Dag::new();
+		let foreign = (0..10).map(|x| other.point(x)).last().unwrap();
+		assert_eq!(dag.prune_to(&[foreign]).unwrap_err(), super::DagError::OutOfRange);
+		dag.depend(&foreign, &super::Index(0));
+		assert_eq!(dag.prune_to(&[super::Index(0)]).unwrap_err(), super::DagError::OutOfRange);
+		assert_eq!(dag.points.len(), 4);
+	}
+
+	#[test]
+	fn test_encode() {
+		let mut dag = super::Dag::new();
+
+		let a = dag.point(10u8);
+		let b = dag.point(20u8);
+		let c = dag.point(30u8);
+
+		dag.depend(&a, &b);
+		dag.depend(&b, &c);
+
+		let bytes = dag.encode(|x, out| out.push(*x)).unwrap();
+		let (decoded, order) = super::Dag::decode(&bytes, |x| x.first().copied()).unwrap();
+
+		assert_eq!(order, Some(vec![c, b, a]));
+		assert_eq!(decoded.build().unwrap(), vec![30, 20, 10]);
+
+		// edge pointing past the end of the points
+		let mut bad = bytes.clone();
+		let edge = bad.len() - 1 - 3 * 4 - 4;
+		bad[edge..edge + 4].copy_from_slice(&7u32.to_le_bytes());
+		assert_eq!(super::Dag::decode(&bad, |x| x.first().copied()).unwrap_err(), super::DagError::OutOfRange);
+
+		// order that puts `a` before what it depends on
+		let mut bad = bytes.clone();
+		let len = bad.len();
+		bad[len - 12..].copy_from_slice(&[0, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0]);
+		assert_eq!(super::Dag::decode(&bad, |x| x.first().copied()).unwrap_err(), super::DagError::Order);
+
+		// truncated
+		assert_eq!(super::Dag::decode(&bytes[..bytes.len() - 1], |x| x.first().copied()).unwrap_err(), super::DagError::Malformed);
+
+		// a point length running past the end of the buffer
+		let mut bad = bytes.clone();
+		bad[9..13].copy_from_slice(&u32::MAX.to_le_bytes());
+		assert_eq!(super::Dag::decode(&bad, |x| x.first().copied()).unwrap_err(), super::DagError::Malformed);
+
+		// a point count larger than the buffer could hold
+		let mut bad = bytes.clone();
+		bad[5..9].copy_from_slice(&u32::MAX.to_le_bytes());
+		assert_eq!(super::Dag::decode(&bad, |x| x.first().copied()).unwrap_err(), super::DagError::Malformed);
+	}
+
+	// property tests over random graphs, using the crate's own rng
+	#[cfg(feature = "rng")]
+	#[test]
+	fn test_random() {
+		use crate::rng::XorShift64;
+
+		let mut rng = XorShift64::new(0x1ce);
+
+		for _ in 0..1000 {
+			let len = (rng.nextu() % 32) as usize + 1;
+
+			// points only ever depend on points of a lower rank, so the graph is acyclic
+			let mut rank = (0..len).collect::<Vec<_>>();
+			for i in (1..len).rev() {
+				rank.swap(i, (rng.nextu() % (i as u64 + 1)) as usize);
+			}
+
+			let mut dag = super::Dag::new();
+			let points = (0..len).map(|x| dag.point(x)).collect::<Vec<_>>();
+			let mut edges = Vec::new();
+
+			for _ in 0..rng.nextu() % (len as u64 * 3) {
+				let a = (rng.nextu() % len as u64) as usize;
+				let b = (rng.nextu() % len as u64) as usize;
+				if rank[a] > rank[b] {
+					dag.depend(&points[a], &points[b]);
+					edges.push((a, b));
+				}
+			}
+
+			// acyclic graphs always build
+			let order = dag.order().expect("acyclic graph failed to build");
+			assert!(dag.check_order(&order).is_ok());
+
+			// every dependency appears before its dependent
+			let position = |x: usize| order.iter().position(|i| *i == points[x]).unwrap();
+			for &(a, b) in &edges {
+				assert!(position(b) < position(a));
+			}
+
+			// the output is a permutation of the input
+			let mut built = dag.clone().build().unwrap();
+			built.sort();
+			assert_eq!(built, (0..len).collect::<Vec<_>>());
+
+			// closing any chain of points into a loop makes the graph cyclic
+			let mut chain = (0..len).collect::<Vec<_>>();
+			chain.sort_by_key(|&x| rank[x]);
+			let start = (rng.nextu() % len as u64) as usize;
+			let end = start + (rng.nextu() % (len - start) as u64) as usize;
+			for w in chain[start..=end].windows(2) {
+				dag.depend(&points[w[1]], &points[w[0]]);
+			}
+			dag.depend(&points[chain[start]], &points[chain[end]]);
+
+			assert!(dag.order().is_err());
+			assert!(dag.build().is_err());
+		}
+	}
+
+	#[test]
+	fn test_keyed() {
+		let mut dag = super::KeyedDag::new();
+
+		dag.depend_keys("tonemap", "bloom");
+		dag.depend_keys("bloom", "hdr");
+		dag.point("tonemap", 30);
+		dag.point("bloom", 20);
+
+		assert_eq!(dag.missing().collect::<Vec<_>>(), vec![&"hdr"]);
+		assert!(matches!(dag.clone().build(), Err(super::DagError::Missing)));
+
+		dag.point("hdr", 10);
+
+		assert_eq!(dag.build().unwrap(), vec![10, 20, 30]);
+	}
 }
 
 
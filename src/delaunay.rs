@@ -0,0 +1,308 @@
+/*!
+delaunay triangulations and voronoi diagrams of 2d points.
+
+```
+# use lykoi_data::{point, geom::Aabb, delaunay::Delaunay};
+let mesh = Delaunay::new(vec![point![0.0, 0.0], point![2.0, 0.0], point![0.0, 2.0], point![2.0, 2.0], point![1.0, 1.0]]);
+assert_eq!(mesh.triangles().len(), 4);
+
+let cells = mesh.voronoi(&Aabb::new(point![0.0, 0.0], point![2.0, 2.0]));
+assert_eq!(cells[4].area(), 2.0);
+```
+*/
+
+use std::fmt::Debug;
+
+use crate::{geom::{Aabb, Polygon}, point::Point};
+
+/**
+a delaunay triangulation, built with the bowyer-watson algorithm.
+
+no point is inside the circumcircle of any triangle, which avoids long thin triangles where possible.
+building takes `O(n^2)` time.
+*/
+#[derive(Debug, Clone)]
+pub struct Delaunay<T>
+where T: Copy + Debug {
+	points: Vec<Point<T, 2>>,
+	triangles: Vec<[usize; 3]>,
+	// the first index of each point's position, for duplicate points
+	canonical: Vec<usize>,
+	// points sharing an edge, including edges only used by the enclosing triangle while building
+	adjacent: Vec<Vec<usize>>,
+}
+impl<T> Delaunay<T>
+where T: Copy + Debug + num_traits::Float {
+	/// triangulates `points`. duplicate points, and points that aren't finite, are left out of the triangulation.
+	pub fn new(points: Vec<Point<T, 2>>) -> Self {
+		let len = points.len();
+
+		// without infinities and nans, the comparison below is a total order
+		let finite: Vec<bool> = points.iter().map(|x| x.unwrap().iter().all(|x| x.is_finite())).collect();
+		let mut order: Vec<usize> = (0..len).filter(|&i| finite[i]).collect();
+		order.sort_by(|&a, &b| points[a].partial_cmp(&points[b]).unwrap_or(std::cmp::Ordering::Equal).then(a.cmp(&b)));
+		let mut canonical: Vec<usize> = (0..len).collect();
+		for pair in order.windows(2) {
+			if points[pair[0]] == points[pair[1]] {
+				canonical[pair[1]] = canonical[pair[0]];
+			}
+		}
+
+		let Some(bounds) = Aabb::from_points(order.iter().map(|&i| points[i])) else {
+			return Self {
+				points,
+				triangles: Vec::new(),
+				canonical,
+				adjacent: Vec::new(),
+			};
+		};
+
+		// a triangle enclosing every point, placed after the real points
+		let size = bounds.size().max_element().unwrap().max(T::one());
+		let far = size * T::from(32).unwrap();
+		let center = bounds.center();
+		let mut vertices = points.clone();
+		vertices.push(center + Point::new([-far, -size]));
+		vertices.push(center + Point::new([far, -size]));
+		vertices.push(center + Point::new([T::zero(), far]));
+
+		let mut triangles = vec![[len, len + 1, len + 2]];
+		for i in (0..len).filter(|&i| finite[i] && canonical[i] == i) {
+			let p = vertices[i];
+			let (bad, good): (Vec<_>, Vec<_>) = triangles
+				.into_iter()
+				.partition(|&[a, b, c]| in_circle(vertices[a], vertices[b], vertices[c], p));
+			triangles = good;
+
+			// the edges around the hole left by the bad triangles
+			let edges: Vec<[usize; 2]> = bad.iter().flat_map(|&[a, b, c]| [[a, b], [b, c], [c, a]]).collect();
+			for &[a, b] in &edges {
+				if !edges.contains(&[b, a]) {
+					triangles.push([a, b, i]);
+				}
+			}
+		}
+
+		let mut adjacent = vec![Vec::new(); len];
+		for &[a, b, c] in &triangles {
+			for (x, y) in [(a, b), (b, c), (c, a)] {
+				if x < len && y < len {
+					adjacent[x].push(y);
+					adjacent[y].push(x);
+				}
+			}
+		}
+		for list in &mut adjacent {
+			list.sort_unstable();
+			list.dedup();
+		}
+
+		triangles.retain(|x| x.iter().all(|&i| i < len));
+		Self {
+			points,
+			triangles,
+			canonical,
+			adjacent,
+		}
+	}
+
+	#[inline]
+	pub fn points(&self) -> &[Point<T, 2>] {
+		&self.points
+	}
+
+	/// the triangles, as counter-clockwise indices into [`Self::points()`].
+	#[inline]
+	pub fn triangles(&self) -> &[[usize; 3]] {
+		&self.triangles
+	}
+
+	/// the points connected to `index` by an edge of the triangulation, in index order.
+	pub fn neighbors(&self, index: usize) -> Vec<usize> {
+		let mut out: Vec<usize> = self.triangles
+			.iter()
+			.filter(|x| x.contains(&self.canonical[index]))
+			.flatten()
+			.copied()
+			.filter(|&x| x != self.canonical[index])
+			.collect();
+		out.sort_unstable();
+		out.dedup();
+		out
+	}
+
+	/**
+	the voronoi cell of every point, clipped to `bounds`, in the same order as [`Self::points()`].
+
+	each cell is the convex, counter-clockwise region of `bounds` closer to its point than any other.
+	duplicate points share a cell. cells entirely outside `bounds`, and those of points that aren't
+	finite, have no points.
+	*/
+	pub fn voronoi(&self, bounds: &Aabb<T, 2>) -> Vec<Polygon<T>> {
+		let corners = vec![
+			bounds.min,
+			Point::new([bounds.max.x(), bounds.min.y()]),
+			bounds.max,
+			Point::new([bounds.min.x(), bounds.max.y()]),
+		];
+		let two = T::one() + T::one();
+		(0..self.points.len())
+			.map(|i| {
+				let site = self.points[self.canonical[i]];
+				if !site.unwrap().iter().all(|x| x.is_finite()) {
+					return Polygon::new(Vec::new());
+				}
+				let cell = self.adjacent[self.canonical[i]].iter().fold(corners.clone(), |cell, &j| {
+					let other = self.points[j];
+					clip(&cell, (site + other) / two, other - site)
+				});
+				Polygon::new(cell)
+			})
+			.collect()
+	}
+}
+
+// returns `true` if `d` is strictly inside the circumcircle of the counter-clockwise triangle `a`, `b`, `c`
+fn in_circle<T>(a: Point<T, 2>, b: Point<T, 2>, c: Point<T, 2>, d: Point<T, 2>) -> bool
+where T: Copy + Debug + num_traits::Float {
+	let (a, b, c) = (a - d, b - d, c - d);
+	let det = a.mag_sq() * b.perp_dot(c) - b.mag_sq() * a.perp_dot(c) + c.mag_sq() * a.perp_dot(b);
+	det > T::zero()
+}
+
+// clips a convex polygon to the half-plane of points `x` where `(x - origin).dot(normal) <= 0`
+fn clip<T>(polygon: &[Point<T, 2>], origin: Point<T, 2>, normal: Point<T, 2>) -> Vec<Point<T, 2>>
+where T: Copy + Debug + num_traits::Float {
+	let mut out = Vec::with_capacity(polygon.len() + 1);
+	for (i, &a) in polygon.iter().enumerate() {
+		let b = polygon[(i + 1) % polygon.len()];
+		let da = (a - origin).dot(normal);
+		let db = (b - origin).dot(normal);
+		if da <= T::zero() {
+			out.push(a);
+		}
+		if (da < T::zero() && db > T::zero()) || (da > T::zero() && db < T::zero()) {
+			out.push(a.lerp(b, da / (da - db)));
+		}
+	}
+	out
+}
+
+#[cfg(test)]
+mod test {
+	use crate::{point, geom::{Triangle, convex_hull}};
+	use super::*;
+
+	// scattered points, without needing an rng
+	fn scatter(count: usize) -> Vec<Point<f64, 2>> {
+		(0..count)
+			.map(|i| point![((i * 7919) % 997) as f64 / 99.7, ((i * 6271) % 991) as f64 / 99.1])
+			.collect()
+	}
+
+	fn check(mesh: &Delaunay<f64>) {
+		let points = mesh.points();
+		let mut area = 0.0;
+		for &[a, b, c] in mesh.triangles() {
+			let tri = Triangle::new(points[a], points[b], points[c]);
+			assert!(tri.signed_area() > 0.0);
+			area += tri.signed_area();
+			// empty circumcircles, with some tolerance for points on the circle
+			for &p in points {
+				let (a, b, c) = (tri.a - p, tri.b - p, tri.c - p);
+				let det = a.mag_sq() * b.perp_dot(c) - b.mag_sq() * a.perp_dot(c) + c.mag_sq() * a.perp_dot(b);
+				assert!(det < 1e-6, "{p} is inside {tri:?}");
+			}
+		}
+		let hull = convex_hull(points);
+		assert!((area - hull.area()).abs() < 1e-9, "{area} != {}", hull.area());
+	}
+
+	#[test]
+	fn test_delaunay() {
+		assert_eq!(Delaunay::<f64>::new(vec![]).triangles().len(), 0);
+		assert_eq!(Delaunay::new(vec![point![0.0, 0.0], point![1.0, 0.0]]).triangles().len(), 0);
+
+		let mesh = Delaunay::new(vec![point![0.0, 0.0], point![1.0, 0.0], point![0.0, 1.0]]);
+		assert_eq!(mesh.triangles(), [[0, 1, 2]]);
+
+		let mesh = Delaunay::new(vec![point![0.0, 0.0], point![2.0, 0.0], point![0.0, 2.0], point![2.0, 2.0], point![1.0, 1.0]]);
+		assert_eq!(mesh.triangles().len(), 4);
+		assert_eq!(mesh.neighbors(4), [0, 1, 2, 3]);
+		assert_eq!(mesh.neighbors(0), [1, 2, 4]);
+		check(&mesh);
+
+		let mesh = Delaunay::new(scatter(200));
+		check(&mesh);
+		// 2n - 2 - h triangles, where h counts every point on the hull's boundary
+		let hull = convex_hull(mesh.points());
+		let boundary = mesh.points().iter().filter(|&&x| hull.closest_point(x).unwrap().distance(x) < 1e-9).count();
+		assert_eq!(mesh.triangles().len(), 2 * 200 - 2 - boundary);
+
+		// a grid, full of cocircular points
+		let grid: Vec<_> = (0..36).map(|i| point![(i % 6) as f64, (i / 6) as f64]).collect();
+		let mesh = Delaunay::new(grid);
+		check(&mesh);
+		assert_eq!(mesh.triangles().len(), 50);
+
+		// duplicates are ignored
+		let mut points = scatter(30);
+		points.extend(scatter(10));
+		let mesh = Delaunay::new(points);
+		check(&mesh);
+		assert!(mesh.triangles().iter().flatten().all(|&x| x < 30));
+		assert_eq!(mesh.neighbors(35), mesh.neighbors(5));
+
+		// as are points that aren't finite
+		let mut points = scatter(30);
+		points.insert(3, point![f64::NAN, 1.0]);
+		points.push(point![f64::INFINITY, 0.0]);
+		let mesh = Delaunay::new(points);
+		assert_eq!(mesh.triangles().len(), Delaunay::new(scatter(30)).triangles().len());
+		assert!(mesh.triangles().iter().flatten().all(|&x| x != 3 && x != 31));
+		assert!(mesh.neighbors(3).is_empty());
+		let cells = mesh.voronoi(&Aabb::new(point![-1.0, -1.0], point![11.0, 11.0]));
+		assert_eq!(cells[3].points.len(), 0);
+		assert_eq!(cells[31].points.len(), 0);
+		assert!((cells.iter().map(|x| x.area()).sum::<f64>() - 144.0).abs() < 1e-9);
+	}
+
+	#[test]
+	fn test_voronoi() {
+		let points = scatter(50);
+		let mesh = Delaunay::new(points.clone());
+		let bounds = Aabb::new(point![-1.0, -1.0], point![11.0, 11.0]);
+		let cells = mesh.voronoi(&bounds);
+
+		let area: f64 = cells.iter().map(|x| x.area()).sum();
+		assert!((area - 144.0).abs() < 1e-9);
+		for (i, cell) in cells.iter().enumerate() {
+			assert!(cell.is_convex());
+			assert_eq!(cell.winding(), Some(crate::geom::Winding::CounterClockwise));
+			assert!(cell.contains(points[i]));
+		}
+
+		// every sample is in the cell of its nearest point
+		for x in 0..24 {
+			for y in 0..24 {
+				let p = point![x as f64 * 0.5 - 0.75, y as f64 * 0.5 - 0.75];
+				let nearest = (0..points.len())
+					.min_by(|&a, &b| points[a].distance(p).total_cmp(&points[b].distance(p)))
+					.unwrap();
+				assert!(cells[nearest].contains(p) || cells[nearest].closest_point(p).unwrap().distance(p) < 1e-9);
+			}
+		}
+
+		// collinear points have no triangles, but still have cells
+		let mesh = Delaunay::new(vec![point![0.0, 0.0], point![1.0, 0.0], point![2.0, 0.0], point![2.0, 0.0]]);
+		assert_eq!(mesh.triangles().len(), 0);
+		let cells = mesh.voronoi(&Aabb::new(point![-1.0, -1.0], point![3.0, 1.0]));
+		assert_eq!(cells.iter().map(|x| x.area()).collect::<Vec<_>>(), [3.0, 2.0, 3.0, 3.0]);
+
+		// a cell outside the bounds
+		let mesh = Delaunay::new(vec![point![0.0, 0.0], point![10.0, 0.0]]);
+		let cells = mesh.voronoi(&Aabb::new(point![-1.0, -1.0], point![1.0, 1.0]));
+		assert_eq!(cells[0].area(), 4.0);
+		assert_eq!(cells[1].points.len(), 0);
+	}
+}
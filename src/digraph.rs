@@ -0,0 +1,503 @@
+/*!
+a general [directed graph](https://en.wikipedia.org/wiki/Directed_graph).
+
+unlike a [`Dag`], a [`Digraph`] is allowed to contain cycles. rather than failing with
+[`DagError::Incomplete`], cycles can be found with
+[`Digraph::components()`] and collapsed with [`Digraph::condense()`], which produces a [`Dag`]
+of the graph's strongly connected components.
+
+[`Digraph::dominators()`] additionally computes the dominator tree of the graph from a root.
+*/
+
+use crate::dag::{Dag, DagError, Index};
+
+/**
+a general directed graph, which may contain cycles.
+
+points are created with [`Self::point()`], and edges with [`Self::edge()`]. the edge
+`from -> to` has the same meaning as [`Dag::depend()`]: "from needs to".
+
+```
+# use lykoi_data::digraph::Digraph;
+let mut graph = Digraph::new();
+
+let a = graph.point("a");
+let b = graph.point("b");
+let c = graph.point("c");
+
+graph.edge(&a, &b).unwrap();
+graph.edge(&b, &a).unwrap(); // cycle!
+graph.edge(&b, &c).unwrap();
+
+assert!(!graph.is_acyclic());
+assert_eq!(graph.cycles(), vec![vec![a, b]]);
+
+// `a` and `b` are collapsed into a single point
+let order = graph.condense().build().unwrap();
+
+assert_eq!(order, vec![vec!["c"], vec!["a", "b"]]);
+```
+*/
+#[derive(Debug, Clone)]
+pub struct Digraph<T> {
+	points: Vec<T>,
+	edges: Vec<(usize, usize)>,
+}
+impl<T: Clone> Digraph<T> {
+	/// constructs a new `Digraph<T>`.
+	pub fn new() -> Self {
+		Self {
+			points: Vec::new(),
+			edges: Vec::new(),
+		}
+	}
+
+	/// creates a new point, and returns an [`Index`] to be used with [`Self::edge()`].
+	#[must_use]
+	pub fn point(&mut self, value: T) -> Index {
+		self.points.push(value);
+		Index(self.points.len() - 1)
+	}
+
+	/// sets up an edge from `from` to `to`. returns [`DagError::OutOfRange`] if either point doesn't exist.
+	pub fn edge(&mut self, from: &Index, to: &Index) -> Result<(), DagError> {
+		let len = self.points.len();
+		if from.0 >= len || to.0 >= len {
+			return Err(DagError::OutOfRange);
+		}
+		self.edges.push((from.0, to.0));
+		Ok(())
+	}
+
+	/// returns the value of a point.
+	pub fn get(&self, index: &Index) -> Option<&T> {
+		self.points.get(index.0)
+	}
+
+	/// returns the amount of points in the graph.
+	pub fn len(&self) -> usize {
+		self.points.len()
+	}
+
+	/// returns `true` if the graph has no points.
+	pub fn is_empty(&self) -> bool {
+		self.points.is_empty()
+	}
+
+	fn successors(&self) -> Vec<Vec<usize>> {
+		let mut succ = vec![Vec::new(); self.points.len()];
+		for &(from, to) in &self.edges {
+			succ[from].push(to);
+		}
+		succ
+	}
+
+	fn predecessors(&self) -> Vec<Vec<usize>> {
+		let mut pred = vec![Vec::new(); self.points.len()];
+		for &(from, to) in &self.edges {
+			pred[to].push(from);
+		}
+		pred
+	}
+
+	/**
+	finds the [strongly connected components](https://en.wikipedia.org/wiki/Strongly_connected_component)
+	of the graph, using [Tarjan's algorithm](https://en.wikipedia.org/wiki/Tarjan%27s_strongly_connected_components_algorithm).
+
+	every point appears in exactly one component, and the points in each component are
+	sorted by [`Index`]. components are returned such that any component appears *after*
+	the components it has edges to, matching the order of [`Dag::build()`].
+	*/
+	pub fn components(&self) -> Vec<Vec<Index>> {
+		let succ = self.successors();
+		let n = self.points.len();
+
+		let mut counter = 0;
+		let mut number = vec![None; n];
+		let mut low = vec![0; n];
+		let mut on_stack = vec![false; n];
+		let mut stack = Vec::new();
+		let mut result = Vec::new();
+
+		for start in 0..n {
+			if number[start].is_some() {
+				continue;
+			}
+
+			// (point, position in its successor list)
+			let mut calls = vec![(start, 0)];
+			number[start] = Some(counter);
+			low[start] = counter;
+			counter += 1;
+			stack.push(start);
+			on_stack[start] = true;
+
+			while let Some(&mut (v, ref mut pos)) = calls.last_mut() {
+				if let Some(&w) = succ[v].get(*pos) {
+					*pos += 1;
+					match number[w] {
+						None => {
+							number[w] = Some(counter);
+							low[w] = counter;
+							counter += 1;
+							stack.push(w);
+							on_stack[w] = true;
+							calls.push((w, 0));
+						},
+						Some(nw) if on_stack[w] => {
+							low[v] = low[v].min(nw);
+						},
+						Some(_) => {},
+					}
+					continue;
+				}
+
+				calls.pop();
+				if let Some(&(parent, _)) = calls.last() {
+					low[parent] = low[parent].min(low[v]);
+				}
+
+				if Some(low[v]) == number[v] {
+					let mut component = Vec::new();
+					while let Some(w) = stack.pop() {
+						on_stack[w] = false;
+						component.push(Index(w));
+						if w == v {
+							break;
+						}
+					}
+					component.sort_by_key(|x| x.0);
+					result.push(component);
+				}
+			}
+		}
+
+		result
+	}
+
+	/// returns `true` if the graph contains no cycles, and so could be built as a [`Dag`].
+	pub fn is_acyclic(&self) -> bool {
+		self.cycles().is_empty()
+	}
+
+	/**
+	returns every component from [`Self::components()`] that forms a cycle.
+
+	that is, any component with more than one point, or a single point with an edge to itself.
+	*/
+	pub fn cycles(&self) -> Vec<Vec<Index>> {
+		let mut looped = vec![false; self.points.len()];
+		for &(from, to) in &self.edges {
+			if from == to {
+				looped[from] = true;
+			}
+		}
+		self.components()
+			.into_iter()
+			.filter(|c| c.len() > 1 || looped[c[0].0])
+			.collect()
+	}
+
+	/**
+	collapses every strongly connected component into a single point, producing a [`Dag`]
+	of the [condensation](https://en.wikipedia.org/wiki/Strongly_connected_component#Definitions).
+
+	each point of the returned `Dag` holds the values of its component, in the same order
+	as [`Self::components()`]. since cycles are collapsed, building the result can't fail.
+	*/
+	pub fn condense(&self) -> Dag<Vec<T>> {
+		let components = self.components();
+
+		let mut owner = vec![0; self.points.len()];
+		let mut dag = Dag::new();
+		let mut indices = Vec::with_capacity(components.len());
+
+		for (i, component) in components.iter().enumerate() {
+			for x in component {
+				owner[x.0] = i;
+			}
+			indices.push(dag.point(component.iter().map(|x| self.points[x.0].clone()).collect()));
+		}
+
+		let mut edges = self.edges
+			.iter()
+			.map(|&(from, to)| (owner[from], owner[to]))
+			.filter(|(from, to)| from != to)
+			.collect::<Vec<_>>();
+		edges.sort_unstable();
+		edges.dedup();
+
+		for (from, to) in edges {
+			dag.depend(&indices[from], &indices[to]);
+		}
+
+		dag
+	}
+
+	/**
+	consumes `self`, converting it into a [`Dag`].
+
+	`Err` is produced if the graph contains a cycle.
+	*/
+	pub fn into_dag(self) -> Result<Dag<T>, DagError> {
+		if !self.is_acyclic() {
+			return Err(DagError::Incomplete);
+		}
+		let mut dag = Dag::new();
+		let indices = self.points.into_iter().map(|x| dag.point(x)).collect::<Vec<_>>();
+		for (from, to) in self.edges {
+			dag.depend(&indices[from], &indices[to]);
+		}
+		Ok(dag)
+	}
+
+	/**
+	computes the [dominator tree](https://en.wikipedia.org/wiki/Dominator_(graph_theory))
+	of every point reachable from `root`, using the Lengauer-Tarjan algorithm.
+
+	a point `a` dominates `b` if every path from `root` to `b` passes through `a`.
+	returns [`DagError::OutOfRange`] if `root` doesn't exist.
+
+	```
+	# use lykoi_data::digraph::Digraph;
+	let mut graph = Digraph::new();
+
+	let entry = graph.point(());
+	let left = graph.point(());
+	let right = graph.point(());
+	let exit = graph.point(());
+
+	graph.edge(&entry, &left).unwrap();
+	graph.edge(&entry, &right).unwrap();
+	graph.edge(&left, &exit).unwrap();
+	graph.edge(&right, &exit).unwrap();
+
+	let dom = graph.dominators(&entry).unwrap();
+
+	assert_eq!(dom.immediate(&left), Some(entry));
+	assert_eq!(dom.immediate(&exit), Some(entry));
+	assert!(!dom.dominates(&left, &exit));
+	```
+	*/
+	pub fn dominators(&self, root: &Index) -> Result<Dominators, DagError> {
+		let n = self.points.len();
+		if root.0 >= n {
+			return Err(DagError::OutOfRange);
+		}
+		let succ = self.successors();
+		let pred = self.predecessors();
+
+		// dfs numbering. everything below is indexed by dfs number, not point.
+		let mut number = vec![None; n];
+		let mut vertex = Vec::new();
+		let mut parent = Vec::new();
+
+		let mut stack = vec![(root.0, 0)];
+		while let Some((v, p)) = stack.pop() {
+			if number[v].is_some() {
+				continue;
+			}
+			number[v] = Some(vertex.len());
+			vertex.push(v);
+			parent.push(p);
+			let dv = vertex.len() - 1;
+			for &w in succ[v].iter().rev() {
+				if number[w].is_none() {
+					stack.push((w, dv));
+				}
+			}
+		}
+
+		let count = vertex.len();
+		let mut semi = (0..count).collect::<Vec<_>>();
+		let mut label = (0..count).collect::<Vec<_>>();
+		let mut ancestor = vec![None; count];
+		let mut idom = vec![0; count];
+		let mut bucket = vec![Vec::new(); count];
+
+		let eval = |v: usize, ancestor: &mut Vec<Option<usize>>, label: &mut Vec<usize>, semi: &Vec<usize>| {
+			if ancestor[v].is_none() {
+				return v;
+			}
+			// compress the path from `v`, iteratively
+			let mut path = Vec::new();
+			let mut x = v;
+			while let Some(a) = ancestor[x] {
+				if ancestor[a].is_none() {
+					break;
+				}
+				path.push(x);
+				x = a;
+			}
+			for &y in path.iter().rev() {
+				let a = ancestor[y].unwrap();
+				if semi[label[a]] < semi[label[y]] {
+					label[y] = label[a];
+				}
+				ancestor[y] = ancestor[a];
+			}
+			label[v]
+		};
+
+		for w in (1..count).rev() {
+			for &v in &pred[vertex[w]] {
+				let Some(v) = number[v] else {
+					continue;
+				};
+				let u = eval(v, &mut ancestor, &mut label, &semi);
+				if semi[u] < semi[w] {
+					semi[w] = semi[u];
+				}
+			}
+			bucket[semi[w]].push(w);
+			ancestor[w] = Some(parent[w]);
+
+			for v in std::mem::take(&mut bucket[parent[w]]) {
+				let u = eval(v, &mut ancestor, &mut label, &semi);
+				idom[v] = if semi[u] < semi[v] { u } else { parent[w] };
+			}
+		}
+
+		for w in 1..count {
+			if idom[w] != semi[w] {
+				idom[w] = idom[idom[w]];
+			}
+		}
+
+		let mut result = vec![None; n];
+		for w in 1..count {
+			result[vertex[w]] = Some(vertex[idom[w]]);
+		}
+
+		Ok(Dominators {
+			root: root.0,
+			idom: result,
+		})
+	}
+}
+
+impl<T: Clone> Default for Digraph<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// the dominator tree of a [`Digraph`]. obtained from [`Digraph::dominators()`].
+#[derive(Debug, Clone)]
+pub struct Dominators {
+	root: usize,
+	idom: Vec<Option<usize>>,
+}
+impl Dominators {
+	/// returns the root the tree was computed from.
+	pub fn root(&self) -> Index {
+		Index(self.root)
+	}
+
+	/**
+	returns the immediate dominator of `point`.
+
+	`None` is returned for the root, and for any point unreachable from the root.
+	*/
+	pub fn immediate(&self, point: &Index) -> Option<Index> {
+		self.idom.get(point.0).copied().flatten().map(Index)
+	}
+
+	/// returns `true` if `point` is reachable from the root.
+	pub fn is_reachable(&self, point: &Index) -> bool {
+		point.0 == self.root || self.immediate(point).is_some()
+	}
+
+	/// returns `true` if every path from the root to `b` passes through `a`. points dominate themselves.
+	pub fn dominates(&self, a: &Index, b: &Index) -> bool {
+		if !self.is_reachable(b) {
+			return false;
+		}
+		let mut x = Some(*b);
+		while let Some(i) = x {
+			if i == *a {
+				return true;
+			}
+			x = self.immediate(&i);
+		}
+		false
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_components() {
+		let mut graph = Digraph::new();
+
+		let p = (0..8).map(|x| graph.point(x)).collect::<Vec<_>>();
+
+		for (a, b) in [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3), (6, 5), (6, 7), (7, 6)] {
+			graph.edge(&p[a], &p[b]).unwrap();
+		}
+
+		let components = graph.components();
+
+		assert_eq!(components, vec![
+			vec![p[3], p[4], p[5]],
+			vec![p[0], p[1], p[2]],
+			vec![p[6], p[7]],
+		]);
+
+		let order = graph.condense().build().unwrap();
+
+		assert_eq!(order[0], vec![3, 4, 5]);
+		assert_eq!(order.len(), 3);
+	}
+
+	#[test]
+	fn test_dominators() {
+		// the example graph from the Lengauer-Tarjan paper
+		let mut graph = Digraph::new();
+
+		let names = ["r", "a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l"];
+		let p = names.map(|x| graph.point(x));
+		let [r, a, b, c, d, e, f, g, h, i, j, k, l] = p;
+
+		for (x, y) in [
+			(r, a), (r, b), (r, c), (a, d), (b, a), (b, d), (b, e), (c, f), (c, g),
+			(d, l), (e, h), (f, i), (g, i), (g, j), (h, e), (h, k), (i, k), (j, i),
+			(k, i), (k, r), (l, h),
+		] {
+			graph.edge(&x, &y).unwrap();
+		}
+
+		let dom = graph.dominators(&r).unwrap();
+
+		let expected = [
+			(a, r), (b, r), (c, r), (d, r), (e, r), (f, c), (g, c),
+			(h, r), (i, r), (j, g), (k, r), (l, d),
+		];
+		for (x, y) in expected {
+			assert_eq!(dom.immediate(&x), Some(y));
+		}
+		assert_eq!(dom.immediate(&r), None);
+		assert!(dom.dominates(&c, &j));
+		assert!(!dom.dominates(&c, &i));
+	}
+
+	#[test]
+	fn test_out_of_range() {
+		let mut other = Digraph::new();
+		let foreign = (0..10).map(|x| other.point(x)).last().unwrap();
+
+		let mut graph = Digraph::new();
+		let a = graph.point(0);
+		assert_eq!(graph.edge(&a, &foreign), Err(DagError::OutOfRange));
+		assert_eq!(graph.edge(&foreign, &a), Err(DagError::OutOfRange));
+		assert!(graph.dominators(&foreign).is_err_and(|x| x == DagError::OutOfRange));
+		assert!(graph.is_acyclic());
+
+		// self loops still count as cycles
+		graph.edge(&a, &a).unwrap();
+		assert_eq!(graph.cycles(), vec![vec![a]]);
+	}
+}
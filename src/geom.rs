@@ -0,0 +1,1177 @@
+/*!
+geometric primitives built on [`Point`], with intersection, overlap and closest point tests.
+
+- [`Aabb`] - an axis aligned bounding box
+- [`Ray`] - a half-line, starting at an origin
+- [`Segment`] - a line segment between two points
+- [`Sphere`] - a sphere, or [`Circle`] in 2d
+- [`Plane`] - a plane, or a line in 2d
+- [`Triangle`]
+- [`Polygon`] - a 2d polygon, with triangulation and simplification
+
+along with [`convex_hull()`] and [`simplify()`] for sets of points.
+
+every primitive is generic over its dimension `N` where it makes sense.
+
+```
+# use lykoi_data::{point, geom::{Aabb, Ray}};
+let aabb = Aabb::new(point![1.0, -1.0], point![3.0, 1.0]);
+let ray = Ray::new(point![0.0, 0.0], point![1.0, 0.0]);
+
+assert_eq!(ray.intersect_aabb(&aabb), Some(1.0));
+```
+*/
+
+use std::fmt::Debug;
+
+use crate::point::Point;
+
+/// an axis aligned bounding box, between `min` and `max`.
+#[derive(Debug, Clone, Copy)]
+pub struct Aabb<T, const N: usize>
+where T: Copy + Debug {
+	pub min: Point<T, N>,
+	pub max: Point<T, N>,
+}
+impl<T, const N: usize> Aabb<T, N>
+where T: Copy + Debug + num_traits::Float {
+	/// constructs a box between two corners. the corners don't have to be ordered.
+	#[inline]
+	pub fn new(a: Point<T, N>, b: Point<T, N>) -> Self {
+		Self {
+			min: a.min_elem(b),
+			max: a.max_elem(b),
+		}
+	}
+
+	/// constructs the smallest box containing every point, or `None` if there are no points.
+	pub fn from_points(points: impl IntoIterator<Item = Point<T, N>>) -> Option<Self> {
+		let mut iter = points.into_iter();
+		let first = iter.next()?;
+		Some(iter.fold(Self::new(first, first), |acc, x| acc.include(x)))
+	}
+
+	#[inline]
+	pub fn center(&self) -> Point<T, N> {
+		(self.min + self.max) / (T::one() + T::one())
+	}
+	#[inline]
+	pub fn size(&self) -> Point<T, N> {
+		self.max - self.min
+	}
+	/// half of [`Self::size()`].
+	#[inline]
+	pub fn extents(&self) -> Point<T, N> {
+		self.size() / (T::one() + T::one())
+	}
+
+	/// returns `true` if `point` is inside the box, or on its edge.
+	#[inline]
+	pub fn contains(&self, point: Point<T, N>) -> bool {
+		(0..N).all(|i| self.min[i] <= point[i] && point[i] <= self.max[i])
+	}
+	/// returns `true` if `other` is entirely inside the box.
+	#[inline]
+	pub fn contains_aabb(&self, other: &Aabb<T, N>) -> bool {
+		self.contains(other.min) && self.contains(other.max)
+	}
+	/// returns `true` if the two boxes overlap. touching boxes overlap.
+	#[inline]
+	pub fn overlaps(&self, other: &Aabb<T, N>) -> bool {
+		(0..N).all(|i| self.min[i] <= other.max[i] && other.min[i] <= self.max[i])
+	}
+
+	/// returns the smallest box containing both boxes.
+	#[inline]
+	pub fn union(&self, other: &Aabb<T, N>) -> Self {
+		Self {
+			min: self.min.min_elem(other.min),
+			max: self.max.max_elem(other.max),
+		}
+	}
+	/// returns the overlapping region of both boxes, or `None` if they don't overlap.
+	#[inline]
+	pub fn intersection(&self, other: &Aabb<T, N>) -> Option<Self> {
+		if !self.overlaps(other) {
+			return None;
+		}
+		Some(Self {
+			min: self.min.max_elem(other.min),
+			max: self.max.min_elem(other.max),
+		})
+	}
+	/// returns the smallest box containing both the box and `point`.
+	#[inline]
+	pub fn include(&self, point: Point<T, N>) -> Self {
+		Self {
+			min: self.min.min_elem(point),
+			max: self.max.max_elem(point),
+		}
+	}
+	/// grows the box by `amount` on every side. a negative `amount` shrinks it.
+	#[inline]
+	pub fn expand(&self, amount: T) -> Self {
+		Self {
+			min: self.min - amount,
+			max: self.max + amount,
+		}
+	}
+
+	/// returns the point in (or on) the box closest to `point`.
+	#[inline]
+	pub fn closest_point(&self, point: Point<T, N>) -> Point<T, N> {
+		point.clamp_elem(self.min, self.max)
+	}
+	/// returns the squared distance from `point` to the box. zero if `point` is inside it.
+	#[inline]
+	pub fn distance_sq(&self, point: Point<T, N>) -> T {
+		self.closest_point(point).distance_sq(point)
+	}
+}
+
+/// a half-line, starting at `origin` and heading towards `direction`.
+#[derive(Debug, Clone, Copy)]
+pub struct Ray<T, const N: usize>
+where T: Copy + Debug {
+	pub origin: Point<T, N>,
+	pub direction: Point<T, N>,
+}
+impl<T, const N: usize> Ray<T, N>
+where T: Copy + Debug + num_traits::Float {
+	/**
+	constructs a new ray.
+
+	`direction` doesn't have to be normalized, but the distances returned by the
+	intersection tests are measured in multiples of its length.
+	*/
+	#[inline]
+	pub fn new(origin: Point<T, N>, direction: Point<T, N>) -> Self {
+		Self {
+			origin,
+			direction,
+		}
+	}
+
+	/// returns the point `t` along the ray.
+	#[inline]
+	pub fn at(&self, t: T) -> Point<T, N> {
+		self.origin + self.direction * t
+	}
+
+	/// returns the point on the ray closest to `point`.
+	#[inline]
+	pub fn closest_point(&self, point: Point<T, N>) -> Point<T, N> {
+		let t = (point - self.origin).dot(self.direction) / self.direction.mag_sq();
+		self.at(t.max(T::zero()))
+	}
+
+	/**
+	returns the distance along the ray to where it enters `aabb`, or `None` if it misses.
+
+	if the ray starts inside the box, `Some(0)` is returned.
+	*/
+	pub fn intersect_aabb(&self, aabb: &Aabb<T, N>) -> Option<T> {
+		self.slab(aabb, T::infinity())
+	}
+
+	// the slab test, limited to distances up to `limit`
+	fn slab(&self, aabb: &Aabb<T, N>, limit: T) -> Option<T> {
+		let mut t_min = T::zero();
+		let mut t_max = limit;
+		for i in 0..N {
+			let (o, d) = (self.origin[i], self.direction[i]);
+			if d == T::zero() {
+				if o < aabb.min[i] || o > aabb.max[i] {
+					return None;
+				}
+				continue;
+			}
+			let t0 = (aabb.min[i] - o) / d;
+			let t1 = (aabb.max[i] - o) / d;
+			t_min = t_min.max(t0.min(t1));
+			t_max = t_max.min(t0.max(t1));
+			if t_min > t_max {
+				return None;
+			}
+		}
+		Some(t_min)
+	}
+
+	/**
+	returns the distance along the ray to where it enters `sphere`, or `None` if it misses.
+
+	if the ray starts inside the sphere, `Some(0)` is returned.
+	*/
+	pub fn intersect_sphere(&self, sphere: &Sphere<T, N>) -> Option<T> {
+		let m = self.origin - sphere.center;
+		let a = self.direction.mag_sq();
+		let b = m.dot(self.direction);
+		let c = m.mag_sq() - sphere.radius * sphere.radius;
+		// outside the sphere, and pointing away
+		if c > T::zero() && b > T::zero() {
+			return None;
+		}
+		let disc = b * b - a * c;
+		if disc < T::zero() {
+			return None;
+		}
+		Some(((-b - disc.sqrt()) / a).max(T::zero()))
+	}
+
+	/// returns the distance along the ray to where it crosses `plane`, or `None` if it doesn't.
+	pub fn intersect_plane(&self, plane: &Plane<T, N>) -> Option<T> {
+		let denom = plane.normal.dot(self.direction);
+		if denom == T::zero() {
+			return None;
+		}
+		let t = -plane.signed_distance(self.origin) / denom;
+		(t >= T::zero()).then_some(t)
+	}
+}
+
+impl<T> Ray<T, 3>
+where T: Copy + Debug + num_traits::Float {
+	/**
+	returns the distance along the ray to where it hits `triangle`, or `None` if it misses.
+
+	uses the [Möller-Trumbore](https://en.wikipedia.org/wiki/M%C3%B6ller%E2%80%93Trumbore_intersection_algorithm)
+	algorithm. both sides of the triangle are hit.
+	*/
+	pub fn intersect_triangle(&self, triangle: &Triangle<T, 3>) -> Option<T> {
+		let e1 = triangle.b - triangle.a;
+		let e2 = triangle.c - triangle.a;
+		let h = self.direction.cross(e2);
+		let det = e1.dot(h);
+		if det.abs() <= T::epsilon() {
+			return None;
+		}
+		let f = T::one() / det;
+		let s = self.origin - triangle.a;
+		let u = f * s.dot(h);
+		if u < T::zero() || u > T::one() {
+			return None;
+		}
+		let q = s.cross(e1);
+		let v = f * self.direction.dot(q);
+		if v < T::zero() || u + v > T::one() {
+			return None;
+		}
+		let t = f * e2.dot(q);
+		(t >= T::zero()).then_some(t)
+	}
+}
+
+/// a line segment between `a` and `b`.
+#[derive(Debug, Clone, Copy)]
+pub struct Segment<T, const N: usize>
+where T: Copy + Debug {
+	pub a: Point<T, N>,
+	pub b: Point<T, N>,
+}
+impl<T, const N: usize> Segment<T, N>
+where T: Copy + Debug + num_traits::Float {
+	#[inline]
+	pub fn new(a: Point<T, N>, b: Point<T, N>) -> Self {
+		Self {
+			a,
+			b,
+		}
+	}
+
+	#[inline]
+	pub fn length(&self) -> T {
+		self.a.distance(self.b)
+	}
+
+	/// returns the point on the segment closest to `point`.
+	#[inline]
+	pub fn closest_point(&self, point: Point<T, N>) -> Point<T, N> {
+		let ab = self.b - self.a;
+		let len = ab.mag_sq();
+		if len == T::zero() {
+			return self.a;
+		}
+		let t = (point - self.a).dot(ab) / len;
+		self.a + ab * t.max(T::zero()).min(T::one())
+	}
+	/// returns the squared distance from `point` to the segment.
+	#[inline]
+	pub fn distance_sq(&self, point: Point<T, N>) -> T {
+		self.closest_point(point).distance_sq(point)
+	}
+
+	/// returns `true` if the segment touches `aabb`.
+	pub fn overlaps_aabb(&self, aabb: &Aabb<T, N>) -> bool {
+		Ray::new(self.a, self.b - self.a).slab(aabb, T::one()).is_some()
+	}
+	/// returns `true` if the segment touches `sphere`.
+	#[inline]
+	pub fn overlaps_sphere(&self, sphere: &Sphere<T, N>) -> bool {
+		self.distance_sq(sphere.center) <= sphere.radius * sphere.radius
+	}
+}
+
+impl<T> Segment<T, 2>
+where T: Copy + Debug + num_traits::Float {
+	/**
+	returns the point where two segments intersect, or `None` if they don't.
+
+	if the segments are collinear and overlap, the first overlapping point along `self` is returned.
+	*/
+	pub fn intersect(&self, other: &Segment<T, 2>) -> Option<Point<T, 2>> {
+		let (zero, one) = (T::zero(), T::one());
+		let r = self.b - self.a;
+		let s = other.b - other.a;
+		let qp = other.a - self.a;
+		let denom = r.perp_dot(s);
+
+		if denom == zero {
+			if qp.perp_dot(r) != zero {
+				// parallel
+				return None;
+			}
+			let len = r.mag_sq();
+			if len == zero {
+				return (other.distance_sq(self.a) == zero).then_some(self.a);
+			}
+			// collinear, find where the projections overlap
+			let t0 = qp.dot(r) / len;
+			let t1 = t0 + s.dot(r) / len;
+			let (lo, hi) = (t0.min(t1).max(zero), t0.max(t1).min(one));
+			return (lo <= hi).then(|| self.a + r * lo);
+		}
+
+		let t = qp.perp_dot(s) / denom;
+		let u = qp.perp_dot(r) / denom;
+		(zero <= t && t <= one && zero <= u && u <= one).then(|| self.a + r * t)
+	}
+}
+
+/// a sphere. in 2d, this is a [`Circle`].
+#[derive(Debug, Clone, Copy)]
+pub struct Sphere<T, const N: usize>
+where T: Copy + Debug {
+	pub center: Point<T, N>,
+	pub radius: T,
+}
+
+pub type Circle<T> = Sphere<T, 2>;
+
+impl<T, const N: usize> Sphere<T, N>
+where T: Copy + Debug + num_traits::Float {
+	#[inline]
+	pub fn new(center: Point<T, N>, radius: T) -> Self {
+		Self {
+			center,
+			radius,
+		}
+	}
+
+	/// returns `true` if `point` is inside the sphere, or on its surface.
+	#[inline]
+	pub fn contains(&self, point: Point<T, N>) -> bool {
+		self.center.distance_sq(point) <= self.radius * self.radius
+	}
+	/// returns `true` if the two spheres overlap.
+	#[inline]
+	pub fn overlaps(&self, other: &Sphere<T, N>) -> bool {
+		let r = self.radius + other.radius;
+		self.center.distance_sq(other.center) <= r * r
+	}
+	/// returns `true` if the sphere overlaps `aabb`.
+	#[inline]
+	pub fn overlaps_aabb(&self, aabb: &Aabb<T, N>) -> bool {
+		aabb.distance_sq(self.center) <= self.radius * self.radius
+	}
+	/// returns `true` if the sphere touches `plane`.
+	#[inline]
+	pub fn overlaps_plane(&self, plane: &Plane<T, N>) -> bool {
+		plane.signed_distance(self.center).abs() <= self.radius
+	}
+
+	/// returns the point on the surface of the sphere closest to `point`.
+	#[inline]
+	pub fn closest_point(&self, point: Point<T, N>) -> Point<T, N> {
+		match (point - self.center).try_normalize() {
+			Some(dir) => self.center + dir * self.radius,
+			// every point on the surface is equally close
+			None => point,
+		}
+	}
+
+	/// returns the smallest box containing the sphere.
+	#[inline]
+	pub fn aabb(&self) -> Aabb<T, N> {
+		Aabb {
+			min: self.center - self.radius,
+			max: self.center + self.radius,
+		}
+	}
+}
+
+/**
+a plane, of all points `x` where `normal · x = distance`. in 2d, this is a line.
+
+`normal` is expected to be normalized.
+*/
+#[derive(Debug, Clone, Copy)]
+pub struct Plane<T, const N: usize>
+where T: Copy + Debug {
+	pub normal: Point<T, N>,
+	pub distance: T,
+}
+impl<T, const N: usize> Plane<T, N>
+where T: Copy + Debug + num_traits::Float {
+	#[inline]
+	pub fn new(normal: Point<T, N>, distance: T) -> Self {
+		Self {
+			normal,
+			distance,
+		}
+	}
+	/// constructs the plane through `point`, facing `normal`.
+	#[inline]
+	pub fn from_point_normal(point: Point<T, N>, normal: Point<T, N>) -> Self {
+		Self::new(normal, normal.dot(point))
+	}
+
+	/// returns the distance from the plane to `point`. positive on the side `normal` faces.
+	#[inline]
+	pub fn signed_distance(&self, point: Point<T, N>) -> T {
+		self.normal.dot(point) - self.distance
+	}
+	/// returns the point on the plane closest to `point`.
+	#[inline]
+	pub fn closest_point(&self, point: Point<T, N>) -> Point<T, N> {
+		point - self.normal * self.signed_distance(point)
+	}
+	/// returns `true` if `aabb` touches the plane.
+	pub fn overlaps_aabb(&self, aabb: &Aabb<T, N>) -> bool {
+		let extents = aabb.extents();
+		let r = (0..N).fold(T::zero(), |acc, i| acc + extents[i] * self.normal[i].abs());
+		self.signed_distance(aabb.center()).abs() <= r
+	}
+}
+
+impl<T> Plane<T, 3>
+where T: Copy + Debug + num_traits::Float {
+	/// constructs the plane through three points, facing counter-clockwise. `None` if the points are collinear.
+	pub fn from_points(a: Point<T, 3>, b: Point<T, 3>, c: Point<T, 3>) -> Option<Self> {
+		let normal = (b - a).cross(c - a).try_normalize()?;
+		Some(Self::from_point_normal(a, normal))
+	}
+}
+
+/// a triangle between `a`, `b` and `c`.
+#[derive(Debug, Clone, Copy)]
+pub struct Triangle<T, const N: usize>
+where T: Copy + Debug {
+	pub a: Point<T, N>,
+	pub b: Point<T, N>,
+	pub c: Point<T, N>,
+}
+impl<T, const N: usize> Triangle<T, N>
+where T: Copy + Debug + num_traits::Float {
+	#[inline]
+	pub fn new(a: Point<T, N>, b: Point<T, N>, c: Point<T, N>) -> Self {
+		Self {
+			a,
+			b,
+			c,
+		}
+	}
+
+	/// returns the point in (or on) the triangle closest to `point`.
+	pub fn closest_point(&self, point: Point<T, N>) -> Point<T, N> {
+		// from "Real-Time Collision Detection" by Christer Ericson, 5.1.5
+		let zero = T::zero();
+		let (a, b, c) = (self.a, self.b, self.c);
+		let ab = b - a;
+		let ac = c - a;
+
+		let ap = point - a;
+		let d1 = ab.dot(ap);
+		let d2 = ac.dot(ap);
+		if d1 <= zero && d2 <= zero {
+			return a;
+		}
+
+		let bp = point - b;
+		let d3 = ab.dot(bp);
+		let d4 = ac.dot(bp);
+		if d3 >= zero && d4 <= d3 {
+			return b;
+		}
+
+		let vc = d1 * d4 - d3 * d2;
+		if vc <= zero && d1 >= zero && d3 <= zero {
+			return a + ab * (d1 / (d1 - d3));
+		}
+
+		let cp = point - c;
+		let d5 = ab.dot(cp);
+		let d6 = ac.dot(cp);
+		if d6 >= zero && d5 <= d6 {
+			return c;
+		}
+
+		let vb = d5 * d2 - d1 * d6;
+		if vb <= zero && d2 >= zero && d6 <= zero {
+			return a + ac * (d2 / (d2 - d6));
+		}
+
+		let va = d3 * d6 - d5 * d4;
+		if va <= zero && d4 - d3 >= zero && d5 - d6 >= zero {
+			return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+		}
+
+		let denom = T::one() / (va + vb + vc);
+		a + ab * (vb * denom) + ac * (vc * denom)
+	}
+
+	/// returns the smallest box containing the triangle.
+	#[inline]
+	pub fn aabb(&self) -> Aabb<T, N> {
+		Aabb::new(self.a, self.b).include(self.c)
+	}
+}
+
+impl<T> Triangle<T, 2>
+where T: Copy + Debug + num_traits::Float {
+	/// returns the area of the triangle. positive if the points are counter-clockwise.
+	#[inline]
+	pub fn signed_area(&self) -> T {
+		(self.b - self.a).perp_dot(self.c - self.a) / (T::one() + T::one())
+	}
+
+	/**
+	returns `true` if `point` is inside the triangle, or on its edge.
+
+	a degenerate triangle, with no area (up to rounding), only contains the points on its longest edge.
+	*/
+	pub fn contains(&self, point: Point<T, 2>) -> bool {
+		let zero = T::zero();
+		let edges = [Segment::new(self.a, self.b), Segment::new(self.b, self.c), Segment::new(self.c, self.a)];
+		let longest = edges.into_iter().reduce(|x, y| if y.length() > x.length() { y } else { x }).unwrap();
+		// rounding error, relative to the size of the triangle
+		let tolerance = longest.length() * T::epsilon().sqrt();
+		if self.signed_area().abs() <= longest.length() * tolerance {
+			return longest.distance_sq(point) <= tolerance * tolerance;
+		}
+		let d0 = (self.b - self.a).perp_dot(point - self.a);
+		let d1 = (self.c - self.b).perp_dot(point - self.b);
+		let d2 = (self.a - self.c).perp_dot(point - self.c);
+		let neg = d0 < zero || d1 < zero || d2 < zero;
+		let pos = d0 > zero || d1 > zero || d2 > zero;
+		!(neg && pos)
+	}
+}
+
+impl<T> Triangle<T, 3>
+where T: Copy + Debug + num_traits::Float {
+	/// returns the normal of the triangle, facing the side its points are counter-clockwise from.
+	#[inline]
+	pub fn normal(&self) -> Point<T, 3> {
+		(self.b - self.a).cross(self.c - self.a).normalize()
+	}
+}
+
+/// the direction a polygon's points go around it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Winding {
+	Clockwise,
+	CounterClockwise,
+}
+
+/// a 2d polygon, made of a closed loop of points.
+#[derive(Debug, Clone)]
+pub struct Polygon<T>
+where T: Copy + Debug {
+	pub points: Vec<Point<T, 2>>,
+}
+impl<T> Polygon<T>
+where T: Copy + Debug + num_traits::Float {
+	#[inline]
+	pub fn new(points: Vec<Point<T, 2>>) -> Self {
+		Self {
+			points,
+		}
+	}
+
+	// each edge, as a pair of points
+	fn edges(&self) -> impl Iterator<Item = (Point<T, 2>, Point<T, 2>)> + '_ {
+		let len = self.points.len();
+		(0..len).map(move |i| (self.points[i], self.points[(i + 1) % len]))
+	}
+
+	/// returns the area of the polygon. positive if the points are counter-clockwise.
+	pub fn signed_area(&self) -> T {
+		self.edges().fold(T::zero(), |acc, (a, b)| acc + a.perp_dot(b)) / (T::one() + T::one())
+	}
+
+	#[inline]
+	pub fn area(&self) -> T {
+		self.signed_area().abs()
+	}
+
+	/// returns the direction of the polygon's points, or `None` if it has no area.
+	pub fn winding(&self) -> Option<Winding> {
+		let area = self.signed_area();
+		if area > T::zero() {
+			Some(Winding::CounterClockwise)
+		} else if area < T::zero() {
+			Some(Winding::Clockwise)
+		} else {
+			None
+		}
+	}
+
+	/// returns the center of mass of the polygon, or `None` if it has no area.
+	pub fn centroid(&self) -> Option<Point<T, 2>> {
+		let area = self.signed_area();
+		if area == T::zero() {
+			return None;
+		}
+		let sum = self.edges().fold(Point::default(), |acc, (a, b)| acc + (a + b) * a.perp_dot(b));
+		let six = (T::one() + T::one()) * (T::one() + T::one() + T::one());
+		Some(sum / (six * area))
+	}
+
+	/// returns `true` if `point` is inside the polygon, using the even-odd rule.
+	pub fn contains(&self, point: Point<T, 2>) -> bool {
+		let mut inside = false;
+		for (a, b) in self.edges() {
+			if (a.y() > point.y()) != (b.y() > point.y()) {
+				let x = a.x() + (point.y() - a.y()) / (b.y() - a.y()) * (b.x() - a.x());
+				if point.x() < x {
+					inside = !inside;
+				}
+			}
+		}
+		inside
+	}
+
+	/**
+	returns the number of times the polygon winds counter-clockwise around `point`.
+
+	`point` is inside the polygon by the non-zero rule if this isn't zero.
+	*/
+	pub fn winding_number(&self, point: Point<T, 2>) -> i32 {
+		let mut count = 0;
+		for (a, b) in self.edges() {
+			let side = (b - a).perp_dot(point - a);
+			if a.y() <= point.y() {
+				if b.y() > point.y() && side > T::zero() {
+					count += 1;
+				}
+			} else if b.y() <= point.y() && side < T::zero() {
+				count -= 1;
+			}
+		}
+		count
+	}
+
+	/// returns the point on the boundary of the polygon closest to `point`, or `None` if it has no points.
+	pub fn closest_point(&self, point: Point<T, 2>) -> Option<Point<T, 2>> {
+		self.edges()
+			.map(|(a, b)| Segment::new(a, b).closest_point(point))
+			.min_by(|x, y| {
+				x.distance_sq(point).partial_cmp(&y.distance_sq(point)).unwrap_or(std::cmp::Ordering::Equal)
+			})
+	}
+
+	/// returns `true` if every interior angle of the polygon is at most 180 degrees, and it doesn't intersect itself.
+	pub fn is_convex(&self) -> bool {
+		let len = self.points.len();
+		if len < 3 {
+			return true;
+		}
+		let mut sign = T::zero();
+		let mut turning = T::zero();
+		for i in 0..len {
+			let a = self.points[i];
+			let b = self.points[(i + 1) % len];
+			let c = self.points[(i + 2) % len];
+			let cross = (b - a).perp_dot(c - b);
+			turning = turning + cross.atan2((b - a).dot(c - b));
+			if cross == T::zero() {
+				continue;
+			}
+			if sign * cross < T::zero() {
+				return false;
+			}
+			sign = cross;
+		}
+		// a star turns the same way at every corner, but goes around more than once
+		turning.abs() < T::from(3.0 * std::f64::consts::PI).unwrap()
+	}
+
+	/**
+	returns `true` if two *convex* polygons overlap, using the separating axis theorem.
+
+	the result is meaningless if either polygon is concave.
+	*/
+	pub fn overlaps(&self, other: &Polygon<T>) -> bool {
+		let project = |poly: &Polygon<T>, axis: Point<T, 2>| {
+			poly.points.iter().fold((T::infinity(), T::neg_infinity()), |(lo, hi), x| {
+				let d = x.dot(axis);
+				(lo.min(d), hi.max(d))
+			})
+		};
+		for (a, b) in self.edges().chain(other.edges()) {
+			let edge = b - a;
+			let axis = Point::new([-edge.y(), edge.x()]);
+			let (lo0, hi0) = project(self, axis);
+			let (lo1, hi1) = project(other, axis);
+			if hi0 < lo1 || hi1 < lo0 {
+				return false;
+			}
+		}
+		true
+	}
+
+	/// returns the smallest box containing the polygon, or `None` if it has no points.
+	pub fn aabb(&self) -> Option<Aabb<T, 2>> {
+		Aabb::from_points(self.points.iter().copied())
+	}
+
+	/**
+	splits a simple polygon into triangles, using ear clipping. the triangles are indices into `points`,
+	and are counter-clockwise regardless of the polygon's winding.
+
+	a polygon with `n` points gives `n - 2` triangles, less any that would have no area.
+	self-intersecting polygons may not be entirely covered. takes `O(n * r)` time, where `r` is the
+	amount of reflex (concave) corners.
+	*/
+	pub fn triangulate(&self) -> Vec<[usize; 3]> {
+		let len = self.points.len();
+		if len < 3 {
+			return Vec::new();
+		}
+
+		// the remaining points as a counter-clockwise linked loop, so clipping a corner is cheap
+		let step = if self.winding() == Some(Winding::Clockwise) { len - 1 } else { 1 };
+		let mut next: Vec<usize> = (0..len).map(|i| (i + step) % len).collect();
+		let mut prev: Vec<usize> = (0..len).map(|i| (i + len - step) % len).collect();
+
+		let corner = |prev: &[usize], next: &[usize], i: usize| {
+			Triangle::new(self.points[prev[i]], self.points[i], self.points[next[i]])
+		};
+		// only corners that aren't convex can be inside an ear
+		let is_ear = |prev: &[usize], next: &[usize], reflex: &[usize], i: usize| {
+			let tri = corner(prev, next, i);
+			tri.signed_area() > T::zero()
+				&& reflex.iter().all(|&x| {
+					// points at the same position as a corner don't block it
+					[prev[i], i, next[i]].contains(&x)
+						|| [tri.a, tri.b, tri.c].contains(&self.points[x])
+						|| !tri.contains(self.points[x])
+				})
+		};
+
+		let mut reflex: Vec<usize> = (0..len).filter(|&i| corner(&prev, &next, i).signed_area() <= T::zero()).collect();
+		let mut ear: Vec<bool> = (0..len).map(|i| is_ear(&prev, &next, &reflex, i)).collect();
+
+		let mut out = Vec::with_capacity(len - 2);
+		let mut remaining = len;
+		let mut misses = 0;
+		let mut i = 0;
+		while remaining >= 3 {
+			let clip = if ear[i] {
+				out.push([prev[i], i, next[i]]);
+				i
+			} else {
+				misses += 1;
+				if misses < remaining {
+					i = next[i];
+					continue;
+				}
+
+				// a full lap without an ear. the flags can go stale if the polygon isn't simple, so check again
+				misses = 0;
+				let mut live = Vec::with_capacity(remaining);
+				for _ in 0..remaining {
+					live.push(i);
+					i = next[i];
+				}
+				for &x in &live {
+					ear[x] = is_ear(&prev, &next, &reflex, x);
+				}
+				if live.iter().any(|&x| ear[x]) {
+					continue;
+				}
+
+				// only collinear corners are left to clip, or the polygon intersects itself
+				match live.into_iter().find(|&x| corner(&prev, &next, x).signed_area() == T::zero()) {
+					Some(x) => x,
+					None => break,
+				}
+			};
+
+			let (a, c) = (prev[clip], next[clip]);
+			next[a] = c;
+			prev[c] = a;
+			remaining -= 1;
+			misses = 0;
+
+			// clipping only makes the neighbouring corners more convex, so only they need checking again
+			reflex.retain(|&x| x != clip && !((x == a || x == c) && corner(&prev, &next, x).signed_area() > T::zero()));
+			ear[a] = is_ear(&prev, &next, &reflex, a);
+			ear[c] = is_ear(&prev, &next, &reflex, c);
+
+			// carry on from the last ear, rather than starting the search over
+			i = c;
+		}
+		out
+	}
+
+	/**
+	removes points that are less than `epsilon` away from the simplified outline, using the douglas-peucker algorithm.
+
+	see [`simplify()`] for open lines.
+	*/
+	pub fn simplify(&self, epsilon: T) -> Polygon<T> {
+		if self.points.len() <= 3 {
+			return self.clone();
+		}
+		// split the loop into two lines at the point farthest from the first
+		let first = self.points[0];
+		let (split, _) = self.points.iter().enumerate().fold((0, T::zero()), |(i, best), (j, x)| {
+			let d = x.distance_sq(first);
+			if d > best { (j, d) } else { (i, best) }
+		});
+		if split == 0 {
+			return Polygon::new(vec![first]);
+		}
+
+		let mut a = simplify(&self.points[..=split], epsilon);
+		let mut tail = self.points[split..].to_vec();
+		tail.push(first);
+		let b = simplify(&tail, epsilon);
+		a.extend_from_slice(&b[1..b.len() - 1]);
+		Polygon::new(a)
+	}
+}
+
+/**
+returns the convex hull of `points` as a counter-clockwise polygon, using andrew's monotone chain algorithm.
+
+points on the hull's edges are left out, as are points that aren't finite.
+
+```
+# use lykoi_data::{point, geom::convex_hull};
+let hull = convex_hull(&[point![0.0, 0.0], point![1.0, 1.0], point![2.0, 0.0], point![1.0, 2.0], point![1.0, 0.0]]);
+assert_eq!(hull.points, [point![0.0, 0.0], point![2.0, 0.0], point![1.0, 2.0]]);
+```
+*/
+pub fn convex_hull<T>(points: &[Point<T, 2>]) -> Polygon<T>
+where T: Copy + Debug + num_traits::Float {
+	// without infinities and nans, the comparison below is a total order
+	let mut sorted: Vec<_> = points.iter().copied().filter(|x| x.unwrap().iter().all(|x| x.is_finite())).collect();
+	sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+	sorted.dedup();
+	if sorted.len() < 3 {
+		return Polygon::new(sorted);
+	}
+
+	// builds one half of the hull, only turning left
+	let half = |iter: &mut dyn Iterator<Item = &Point<T, 2>>| {
+		let mut out: Vec<Point<T, 2>> = Vec::new();
+		for &p in iter {
+			while let [.., a, b] = out[..] {
+				if (b - a).perp_dot(p - a) > T::zero() {
+					break;
+				}
+				out.pop();
+			}
+			out.push(p);
+		}
+		// the last point starts the other half
+		out.pop();
+		out
+	};
+	let mut hull = half(&mut sorted.iter());
+	hull.extend(half(&mut sorted.iter().rev()));
+	Polygon::new(hull)
+}
+
+/**
+simplifies an open line, removing points that are less than `epsilon` away from the simplified line,
+using the douglas-peucker algorithm. the first and last points are always kept.
+*/
+pub fn simplify<T, const N: usize>(points: &[Point<T, N>], epsilon: T) -> Vec<Point<T, N>>
+where T: Copy + Debug + num_traits::Float {
+	if points.len() <= 2 {
+		return points.to_vec();
+	}
+	let mut keep = vec![false; points.len()];
+	keep[0] = true;
+	keep[points.len() - 1] = true;
+
+	let epsilon_sq = epsilon * epsilon;
+	let mut stack = vec![(0, points.len() - 1)];
+	while let Some((start, end)) = stack.pop() {
+		let segment = Segment::new(points[start], points[end]);
+		let (index, distance) = (start + 1..end)
+			.map(|i| (i, segment.distance_sq(points[i])))
+			.fold((start, T::zero()), |acc, x| if x.1 > acc.1 { x } else { acc });
+		if distance > epsilon_sq {
+			keep[index] = true;
+			stack.push((start, index));
+			stack.push((index, end));
+		}
+	}
+	points.iter().zip(keep).filter(|x| x.1).map(|x| *x.0).collect()
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::point;
+	use super::*;
+
+	fn approx<const N: usize>(a: Point<f64, N>, b: Point<f64, N>) -> bool {
+		a.distance(b) < 1e-9
+	}
+
+	#[test]
+	fn test_aabb() {
+		let a = Aabb::new(point![2.0, 0.0], point![0.0, 2.0]);
+		let b = Aabb::new(point![1.0, 1.0], point![3.0, 4.0]);
+
+		assert_eq!(a.min.unpack(), &[0.0, 0.0]);
+		assert!(a.overlaps(&b));
+		assert!(!a.overlaps(&Aabb::new(point![2.5, 0.0], point![3.0, 1.0])));
+		assert!(a.contains(point![2.0, 1.0]));
+		assert!(!a.contains(point![2.1, 1.0]));
+
+		let u = a.union(&b);
+		assert_eq!((u.min.unpack(), u.max.unpack()), (&[0.0, 0.0], &[3.0, 4.0]));
+		let i = a.intersection(&b).unwrap();
+		assert_eq!((i.min.unpack(), i.max.unpack()), (&[1.0, 1.0], &[2.0, 2.0]));
+		let e = a.expand(1.0);
+		assert_eq!((e.min.unpack(), e.max.unpack()), (&[-1.0, -1.0], &[3.0, 3.0]));
+		assert!(u.contains_aabb(&b));
+
+		assert_eq!(a.closest_point(point![5.0, 1.0]).unpack(), &[2.0, 1.0]);
+		assert_eq!(a.distance_sq(point![5.0, 6.0]), 25.0);
+	}
+
+	#[test]
+	fn test_ray() {
+		let aabb = Aabb::new(point![1.0, 1.0, 1.0], point![3.0, 3.0, 3.0]);
+		let ray = Ray::new(point![0.0, 2.0, 2.0], point![1.0, 0.0, 0.0]);
+		assert_eq!(ray.intersect_aabb(&aabb), Some(1.0));
+		assert_eq!(Ray::new(point![0.0, 0.0, 0.0], point![1.0, 1.0, 1.0]).intersect_aabb(&aabb), Some(1.0));
+		assert_eq!(Ray::new(point![0.0, 0.0, 0.0], point![-1.0, 0.0, 0.0]).intersect_aabb(&aabb), None);
+		assert_eq!(Ray::new(point![2.0, 2.0, 2.0], point![0.0, 1.0, 0.0]).intersect_aabb(&aabb), Some(0.0));
+
+		let sphere = Sphere::new(point![5.0, 0.0, 0.0], 2.0);
+		let ray = Ray::new(point![0.0, 0.0, 0.0], point![1.0, 0.0, 0.0]);
+		assert_eq!(ray.intersect_sphere(&sphere), Some(3.0));
+		// 3-4-5 triangle: the ray passes 1.2 units from the center
+		let ray = Ray::new(point![0.0, 0.0, 0.0], point![4.0, 3.0, 0.0] / 5.0);
+		assert!(ray.intersect_sphere(&Sphere::new(point![5.0, 0.0, 0.0], 1.0)).is_none());
+		assert!(ray.intersect_sphere(&Sphere::new(point![5.0, 0.0, 0.0], 3.1)).is_some());
+
+		let plane = Plane::new(point![0.0, 1.0, 0.0], 2.0);
+		let ray = Ray::new(point![0.0, 0.0, 0.0], point![1.0, 1.0, 0.0]);
+		assert_eq!(ray.intersect_plane(&plane), Some(2.0));
+		assert_eq!(Ray::new(point![0.0, 0.0, 0.0], point![1.0, 0.0, 0.0]).intersect_plane(&plane), None);
+
+		let tri = Triangle::new(point![0.0, 0.0, 2.0], point![2.0, 0.0, 2.0], point![0.0, 2.0, 2.0]);
+		let ray = Ray::new(point![0.5, 0.5, 0.0], point![0.0, 0.0, 1.0]);
+		assert_eq!(ray.intersect_triangle(&tri), Some(2.0));
+		let ray = Ray::new(point![1.5, 1.5, 0.0], point![0.0, 0.0, 1.0]);
+		assert_eq!(ray.intersect_triangle(&tri), None);
+
+		assert_eq!(Ray::new(point![0.0, 0.0], point![1.0, 0.0]).closest_point(point![-3.0, 2.0]).unpack(), &[0.0, 0.0]);
+	}
+
+	#[test]
+	fn test_segment() {
+		let a = Segment::new(point![0.0, 0.0], point![4.0, 4.0]);
+		let b = Segment::new(point![0.0, 4.0], point![4.0, 0.0]);
+		assert_eq!(a.intersect(&b).unwrap().unpack(), &[2.0, 2.0]);
+		assert!(a.intersect(&Segment::new(point![3.0, 0.0], point![5.0, 0.0])).is_none());
+		// collinear and overlapping
+		let c = Segment::new(point![2.0, 2.0], point![6.0, 6.0]);
+		assert_eq!(a.intersect(&c).unwrap().unpack(), &[2.0, 2.0]);
+		// parallel
+		assert!(a.intersect(&Segment::new(point![1.0, 0.0], point![5.0, 4.0])).is_none());
+
+		assert_eq!(a.closest_point(point![4.0, 0.0]).unpack(), &[2.0, 2.0]);
+		assert_eq!(a.closest_point(point![6.0, 5.0]).unpack(), &[4.0, 4.0]);
+
+		let aabb = Aabb::new(point![0.5, 0.5], point![1.5, 1.5]);
+		assert!(a.overlaps_aabb(&aabb));
+		assert!(!b.overlaps_aabb(&aabb));
+		assert!(a.overlaps_sphere(&Circle::new(point![4.0, 0.0], 3.0)));
+		assert!(!a.overlaps_sphere(&Circle::new(point![4.0, 0.0], 2.8)));
+	}
+
+	#[test]
+	fn test_shapes() {
+		let circle = Circle::new(point![0.0, 0.0], 2.0);
+		assert!(circle.contains(point![1.0, 1.0]));
+		assert!(circle.overlaps(&Circle::new(point![3.0, 0.0], 1.0)));
+		assert!(!circle.overlaps(&Circle::new(point![3.0, 0.0], 0.9)));
+		assert!(circle.overlaps_aabb(&Aabb::new(point![1.0, 1.0], point![3.0, 3.0])));
+		assert!(!circle.overlaps_aabb(&Aabb::new(point![1.5, 1.5], point![3.0, 3.0])));
+		assert_eq!(circle.closest_point(point![0.0, 5.0]).unpack(), &[0.0, 2.0]);
+
+		let plane = Plane::from_point_normal(point![0.0, 0.0, 1.0], point![0.0, 0.0, 1.0]);
+		assert_eq!(plane.signed_distance(point![3.0, 4.0, -2.0]), -3.0);
+		assert_eq!(plane.closest_point(point![3.0, 4.0, -2.0]).unpack(), &[3.0, 4.0, 1.0]);
+		assert!(plane.overlaps_aabb(&Aabb::new(point![0.0, 0.0, 0.0], point![1.0, 1.0, 1.0])));
+		assert!(!plane.overlaps_aabb(&Aabb::new(point![0.0, 0.0, 1.5], point![1.0, 1.0, 2.0])));
+		assert!(Sphere::new(point![0.0, 0.0, 3.0], 2.0).overlaps_plane(&plane));
+		let plane = Plane::from_points(point![0.0, 0.0, 0.0], point![1.0, 0.0, 0.0], point![0.0, 1.0, 0.0]).unwrap();
+		assert_eq!(plane.normal.unpack(), &[0.0, 0.0, 1.0]);
+
+		let tri = Triangle::new(point![0.0, 0.0], point![4.0, 0.0], point![0.0, 4.0]);
+		assert!(tri.contains(point![1.0, 1.0]));
+		assert!(!tri.contains(point![3.0, 3.0]));
+		assert_eq!(tri.signed_area(), 8.0);
+		assert!(approx(tri.closest_point(point![3.0, 3.0]), point![2.0, 2.0]));
+		assert!(approx(tri.closest_point(point![-1.0, -1.0]), point![0.0, 0.0]));
+		assert!(approx(tri.closest_point(point![2.0, -3.0]), point![2.0, 0.0]));
+		assert!(approx(tri.closest_point(point![1.0, 1.0]), point![1.0, 1.0]));
+
+		// degenerate triangles only contain the points along them
+		let flat = Triangle::new(point![0.0, 0.0], point![4.0, 0.0], point![2.0, 0.0]);
+		assert!(flat.contains(point![1.0, 0.0]));
+		assert!(!flat.contains(point![5.0, 0.0]));
+		assert!(!flat.contains(point![1.0, 1.0]));
+		// points on the edge only up to rounding
+		let (a, b) = (point![0.1, 0.2], point![0.7, 1.3]);
+		let flat = Triangle::new(a, b, a.lerp(b, 0.3));
+		for t in [0.1, 0.45, 0.9] {
+			assert!(flat.contains(a.lerp(b, t)), "{t}");
+		}
+		assert!(!flat.contains(a.lerp(b, 1.1)));
+		assert!(!flat.contains(a.lerp(b, 0.5) + point![1e-3, 0.0]));
+		let single = Triangle::new(point![1.0, 1.0], point![1.0, 1.0], point![1.0, 1.0]);
+		assert!(single.contains(point![1.0, 1.0]));
+		assert!(!single.contains(point![0.0, 0.0]));
+	}
+
+	#[test]
+	fn test_polygon() {
+		// an "L" shape
+		let l = Polygon::new(vec![
+			point![0.0, 0.0], point![2.0, 0.0], point![2.0, 1.0],
+			point![1.0, 1.0], point![1.0, 2.0], point![0.0, 2.0],
+		]);
+		assert_eq!(l.signed_area(), 3.0);
+		assert!(l.contains(point![0.5, 1.5]));
+		assert!(!l.contains(point![1.5, 1.5]));
+		assert!(!l.is_convex());
+		assert_eq!(l.closest_point(point![1.5, 1.5]).unwrap().unpack(), &[1.5, 1.0]);
+
+		let square = Polygon::new(vec![point![0.0, 0.0], point![2.0, 0.0], point![2.0, 2.0], point![0.0, 2.0]]);
+		let diamond = Polygon::new(vec![point![3.0, 1.0], point![4.0, 0.0], point![5.0, 1.0], point![4.0, 2.0]]);
+		assert!(square.is_convex());
+		assert!(!square.overlaps(&diamond));
+		let star = Polygon::new((0..5).map(|i| {
+			let a = (i * 2) as f64 / 5.0 * std::f64::consts::TAU;
+			point![a.cos(), a.sin()]
+		}).collect());
+		assert!(!star.is_convex());
+		let diamond = Polygon::new(vec![point![1.5, 1.0], point![2.5, 0.0], point![3.5, 1.0], point![2.5, 2.0]]);
+		assert!(square.overlaps(&diamond));
+	}
+
+	#[test]
+	fn test_polygon_ops() {
+		let l = Polygon::new(vec![
+			point![0.0, 0.0], point![2.0, 0.0], point![2.0, 1.0],
+			point![1.0, 1.0], point![1.0, 2.0], point![0.0, 2.0],
+		]);
+		assert_eq!(l.winding(), Some(Winding::CounterClockwise));
+		assert!(approx(l.centroid().unwrap(), point![5.0 / 6.0, 5.0 / 6.0]));
+		assert_eq!(l.winding_number(point![0.5, 1.5]), 1);
+		assert_eq!(l.winding_number(point![1.5, 1.5]), 0);
+
+		let mut reversed = l.clone();
+		reversed.points.reverse();
+		assert_eq!(reversed.winding(), Some(Winding::Clockwise));
+		assert_eq!(reversed.area(), 3.0);
+		assert_eq!(reversed.winding_number(point![0.5, 1.5]), -1);
+		assert!(approx(reversed.centroid().unwrap(), l.centroid().unwrap()));
+		assert_eq!(Polygon::new(vec![point![0.0, 0.0], point![1.0, 1.0]]).centroid(), None);
+
+		for poly in [&l, &reversed] {
+			let tris = poly.triangulate();
+			assert_eq!(tris.len(), 4);
+			let area = tris.iter().fold(0.0, |acc, &[a, b, c]| {
+				let tri = Triangle::new(poly.points[a], poly.points[b], poly.points[c]);
+				assert!(tri.signed_area() > 0.0);
+				assert!(poly.contains((tri.a + tri.b + tri.c) / 3.0));
+				acc + tri.signed_area()
+			});
+			assert_eq!(area, 3.0);
+		}
+
+		// a collinear point on an edge
+		let square = Polygon::new(vec![point![0.0, 0.0], point![1.0, 0.0], point![2.0, 0.0], point![2.0, 2.0], point![0.0, 2.0]]);
+		let tris = square.triangulate();
+		assert_eq!(tris.len(), 3);
+		assert_eq!(tris.iter().map(|&[a, b, c]| Triangle::new(square.points[a], square.points[b], square.points[c]).signed_area()).sum::<f64>(), 4.0);
+
+		// a comb, with lots of reflex corners
+		let mut comb = vec![point![0.0, 0.0], point![200.0, 0.0]];
+		for i in (0..100).rev() {
+			comb.push(point![i as f64 * 2.0 + 2.0, 10.0]);
+			comb.push(point![i as f64 * 2.0 + 1.0, 1.0]);
+		}
+		let comb = Polygon::new(comb);
+		let tris = comb.triangulate();
+		assert_eq!(tris.len(), comb.points.len() - 2);
+		let area: f64 = tris.iter().map(|&[a, b, c]| Triangle::new(comb.points[a], comb.points[b], comb.points[c]).signed_area()).sum();
+		assert!((area - comb.area()).abs() < 1e-9);
+	}
+
+	#[test]
+	fn test_hull() {
+		assert_eq!(convex_hull::<f64>(&[]).points.len(), 0);
+		assert_eq!(convex_hull(&[point![1.0, 1.0], point![1.0, 1.0]]).points.len(), 1);
+
+		let mut points = vec![];
+		for x in 0..5 {
+			for y in 0..5 {
+				points.push(point![x as f64, y as f64]);
+			}
+		}
+		points.push(point![2.0, 6.0]);
+		let hull = convex_hull(&points);
+		assert_eq!(hull.points, [point![0.0, 0.0], point![4.0, 0.0], point![4.0, 4.0], point![2.0, 6.0], point![0.0, 4.0]]);
+		assert!(hull.is_convex());
+		assert_eq!(hull.winding(), Some(Winding::CounterClockwise));
+
+		// points that aren't finite are ignored
+		let mut broken = points.clone();
+		broken.insert(7, point![f64::NAN, 1.0]);
+		broken.push(point![f64::NEG_INFINITY, 0.0]);
+		assert_eq!(convex_hull(&broken).points, hull.points);
+		assert!(points.iter().all(|&x| hull.contains(x) || hull.closest_point(x).unwrap().distance(x) < 1e-9));
+	}
+
+	#[test]
+	fn test_simplify() {
+		let line = [point![0.0, 0.0], point![1.0, 0.1], point![2.0, -0.1], point![3.0, 5.0], point![4.0, 6.0], point![5.0, 7.0]];
+		assert_eq!(simplify(&line, 0.5), [point![0.0, 0.0], point![2.0, -0.1], point![3.0, 5.0], point![5.0, 7.0]]);
+		// only the collinear point is removed
+		assert_eq!(simplify(&line, 0.0).len(), line.len() - 1);
+		assert_eq!(simplify(&line, 100.0), [line[0], line[5]]);
+
+		let circle = Polygon::new((0..64).map(|i| {
+			let a = i as f64 / 64.0 * std::f64::consts::TAU;
+			point![a.cos(), a.sin()]
+		}).collect());
+		let simple = circle.simplify(0.05);
+		assert!(simple.points.len() < 24 && simple.points.len() > 4);
+		assert!(circle.points.iter().all(|&x| simple.closest_point(x).unwrap().distance(x) <= 0.05));
+		assert!(simple.points.iter().all(|x| circle.points.contains(x)));
+	}
+}
@@ -0,0 +1,406 @@
+/*!
+utilities for integer grids of [`Point<i32, 2>`] cells.
+
+- [`line()`] and [`circle()`] - rasterization iterators
+- [`neighbors4()`] and [`neighbors8()`]
+- [`manhattan()`] and [`chebyshev()`] distances
+- [`flood_fill()`]
+- [`dijkstra()`] and [`astar()`] - shortest paths over a cost function
+
+the grid itself is never stored, and is instead described by callbacks. callbacks must reject
+cells outside of some finite region, or searches for unreachable cells will never end.
+
+```
+# use lykoi_data::{point, grid::{self, Connectivity}};
+let walls = [point![1, 0], point![1, 1]];
+let path = grid::astar(point![0, 0], point![2, 0], Connectivity::Four, |_, to| {
+	let inside = (0..3).contains(&to.x()) && (0..3).contains(&to.y());
+	(inside && !walls.contains(&to)).then_some(1)
+});
+
+assert_eq!(path.map(|x| x.len()), Some(7));
+```
+*/
+
+use std::{cmp::Reverse, collections::{BinaryHeap, HashMap, HashSet, VecDeque}};
+
+use crate::point::Point;
+
+/// the cells a cell is connected to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+	/// orthogonal neighbors, as in [`neighbors4()`].
+	Four,
+	/// orthogonal and diagonal neighbors, as in [`neighbors8()`].
+	Eight,
+}
+impl Connectivity {
+	/// the neighbors of `point` with this connectivity.
+	pub fn neighbors(self, point: Point<i32, 2>) -> impl Iterator<Item = Point<i32, 2>> {
+		let count = match self {
+			Connectivity::Four => 4,
+			Connectivity::Eight => 8,
+		};
+		neighbors8(point).enumerate().filter(move |&(i, _)| i % (8 / count) == 0).map(|(_, x)| x)
+	}
+
+	/// the number of steps between `a` and `b` on an empty grid.
+	#[inline]
+	pub fn distance(self, a: Point<i32, 2>, b: Point<i32, 2>) -> i32 {
+		match self {
+			Connectivity::Four => manhattan(a, b),
+			Connectivity::Eight => chebyshev(a, b),
+		}
+	}
+}
+
+/// the orthogonal neighbors of `point`, counter-clockwise from `+x`.
+pub fn neighbors4(point: Point<i32, 2>) -> impl Iterator<Item = Point<i32, 2>> {
+	[[1, 0], [0, 1], [-1, 0], [0, -1]].into_iter().map(move |x| point + Point::new(x))
+}
+
+/// the orthogonal and diagonal neighbors of `point`, counter-clockwise from `+x`.
+pub fn neighbors8(point: Point<i32, 2>) -> impl Iterator<Item = Point<i32, 2>> {
+	[[1, 0], [1, 1], [0, 1], [-1, 1], [-1, 0], [-1, -1], [0, -1], [1, -1]]
+		.into_iter()
+		.map(move |x| point + Point::new(x))
+}
+
+/// the distance between `a` and `b` when moving orthogonally.
+#[inline]
+pub fn manhattan(a: Point<i32, 2>, b: Point<i32, 2>) -> i32 {
+	(a - b).abs().sum()
+}
+
+/// the distance between `a` and `b` when moving orthogonally and diagonally.
+#[inline]
+pub fn chebyshev(a: Point<i32, 2>, b: Point<i32, 2>) -> i32 {
+	(a - b).abs().max_element().unwrap()
+}
+
+/**
+the cells on the line from `a` to `b`, including both ends, using bresenham's algorithm.
+
+consecutive cells are 8-connected.
+
+```
+# use lykoi_data::{point, grid};
+let cells: Vec<_> = grid::line(point![0, 0], point![3, 1]).collect();
+assert_eq!(cells, [point![0, 0], point![1, 0], point![2, 1], point![3, 1]]);
+```
+*/
+pub fn line(a: Point<i32, 2>, b: Point<i32, 2>) -> Line {
+	let delta = b - a;
+	Line {
+		current: a,
+		end: b,
+		dx: delta.x().abs(),
+		dy: -delta.y().abs(),
+		step: delta.signum(),
+		error: delta.x().abs() - delta.y().abs(),
+		done: false,
+	}
+}
+
+/// the iterator returned by [`line()`].
+#[derive(Debug, Clone)]
+pub struct Line {
+	current: Point<i32, 2>,
+	end: Point<i32, 2>,
+	dx: i32,
+	dy: i32,
+	step: Point<i32, 2>,
+	error: i32,
+	done: bool,
+}
+impl Iterator for Line {
+	type Item = Point<i32, 2>;
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done {
+			return None;
+		}
+		let out = self.current;
+		if self.current == self.end {
+			self.done = true;
+			return Some(out);
+		}
+		let double = self.error * 2;
+		if double >= self.dy {
+			self.error += self.dy;
+			self.current.set_x(self.current.x() + self.step.x());
+		}
+		if double <= self.dx {
+			self.error += self.dx;
+			self.current.set_y(self.current.y() + self.step.y());
+		}
+		Some(out)
+	}
+}
+
+/**
+the cells on the outline of a circle, using the midpoint circle algorithm.
+
+every cell is returned once. a `radius` of zero returns only `center`, and a negative `radius` returns nothing.
+cells are returned in groups of symmetric points, not in order around the circle.
+*/
+pub fn circle(center: Point<i32, 2>, radius: i32) -> Circle {
+	Circle {
+		center,
+		x: radius,
+		y: 0,
+		error: 1 - radius,
+		buffer: [Point::default(); 8],
+		len: 0,
+		index: 0,
+	}
+}
+
+/// the iterator returned by [`circle()`].
+#[derive(Debug, Clone)]
+pub struct Circle {
+	center: Point<i32, 2>,
+	x: i32,
+	y: i32,
+	error: i32,
+	// the unique cells of the current octant step
+	buffer: [Point<i32, 2>; 8],
+	len: usize,
+	index: usize,
+}
+impl Iterator for Circle {
+	type Item = Point<i32, 2>;
+	fn next(&mut self) -> Option<Self::Item> {
+		while self.index >= self.len {
+			if self.x < self.y {
+				return None;
+			}
+			let (x, y) = (self.x, self.y);
+			self.len = 0;
+			self.index = 0;
+			for offset in [[x, y], [y, x], [-y, x], [-x, y], [-x, -y], [-y, -x], [y, -x], [x, -y]] {
+				let cell = self.center + Point::new(offset);
+				if !self.buffer[..self.len].contains(&cell) {
+					self.buffer[self.len] = cell;
+					self.len += 1;
+				}
+			}
+
+			self.y += 1;
+			if self.error < 0 {
+				self.error += 2 * self.y + 1;
+			} else {
+				self.x -= 1;
+				self.error += 2 * (self.y - self.x) + 1;
+			}
+		}
+		self.index += 1;
+		Some(self.buffer[self.index - 1])
+	}
+}
+
+/**
+every cell reachable from `start` through cells where `inside` returns `true`, in breadth first order.
+
+returns nothing if `start` itself isn't inside.
+*/
+pub fn flood_fill(
+	start: Point<i32, 2>,
+	connectivity: Connectivity,
+	mut inside: impl FnMut(Point<i32, 2>) -> bool,
+) -> Vec<Point<i32, 2>> {
+	let mut out = Vec::new();
+	if !inside(start) {
+		return out;
+	}
+	let mut seen = HashSet::from([start]);
+	let mut queue = VecDeque::from([start]);
+	while let Some(cell) = queue.pop_front() {
+		out.push(cell);
+		for next in connectivity.neighbors(cell) {
+			if !seen.contains(&next) && inside(next) {
+				seen.insert(next);
+				queue.push_back(next);
+			}
+		}
+	}
+	out
+}
+
+/**
+the cheapest path from `start` to `goal`, including both, or `None` if there is no path.
+
+`cost(from, to)` gives the cost of stepping between two neighboring cells,
+or `None` if the step isn't allowed.
+*/
+pub fn dijkstra(
+	start: Point<i32, 2>,
+	goal: Point<i32, 2>,
+	connectivity: Connectivity,
+	cost: impl FnMut(Point<i32, 2>, Point<i32, 2>) -> Option<u32>,
+) -> Option<Vec<Point<i32, 2>>> {
+	search(start, goal, connectivity, cost, |_| 0)
+}
+
+/**
+the cheapest path from `start` to `goal`, including both, or `None` if there is no path.
+
+this is [`dijkstra()`], guided towards `goal` by [`Connectivity::distance()`]. the path is only
+guaranteed to be the cheapest if every step costs at least 1.
+*/
+pub fn astar(
+	start: Point<i32, 2>,
+	goal: Point<i32, 2>,
+	connectivity: Connectivity,
+	cost: impl FnMut(Point<i32, 2>, Point<i32, 2>) -> Option<u32>,
+) -> Option<Vec<Point<i32, 2>>> {
+	search(start, goal, connectivity, cost, |x| connectivity.distance(x, goal) as u32)
+}
+
+fn search(
+	start: Point<i32, 2>,
+	goal: Point<i32, 2>,
+	connectivity: Connectivity,
+	mut cost: impl FnMut(Point<i32, 2>, Point<i32, 2>) -> Option<u32>,
+	heuristic: impl Fn(Point<i32, 2>) -> u32,
+) -> Option<Vec<Point<i32, 2>>> {
+	// best known cost to each cell, and the cell it was reached from
+	let mut best: HashMap<Point<i32, 2>, (u32, Point<i32, 2>)> = HashMap::from([(start, (0, start))]);
+	let mut queue = BinaryHeap::from([Reverse((heuristic(start), 0, start))]);
+
+	while let Some(Reverse((_, total, cell))) = queue.pop() {
+		if cell == goal {
+			let mut path = vec![goal];
+			let mut current = goal;
+			while current != start {
+				current = best[&current].1;
+				path.push(current);
+			}
+			path.reverse();
+			return Some(path);
+		}
+		// stale entry, already reached more cheaply
+		if total > best[&cell].0 {
+			continue;
+		}
+		for next in connectivity.neighbors(cell) {
+			let Some(step) = cost(cell, next) else {
+				continue;
+			};
+			let total = total.saturating_add(step);
+			if best.get(&next).is_none_or(|&(x, _)| total < x) {
+				best.insert(next, (total, cell));
+				queue.push(Reverse((total.saturating_add(heuristic(next)), total, next)));
+			}
+		}
+	}
+	None
+}
+
+#[cfg(test)]
+mod test {
+	use crate::point;
+	use super::*;
+
+	const MAZE: [&str; 6] = [
+		"..#.....",
+		"..#.##..",
+		"..#.#.#.",
+		"....##..",
+		"###.....",
+		"........",
+	];
+
+	fn open(cell: Point<i32, 2>) -> bool {
+		(0..6).contains(&cell.y())
+			&& (0..8).contains(&cell.x())
+			&& MAZE[cell.y() as usize].as_bytes()[cell.x() as usize] == b'.'
+	}
+
+	#[test]
+	fn test_distance() {
+		let a = point![1, -2];
+		let b = point![4, 3];
+		assert_eq!(manhattan(a, b), 8);
+		assert_eq!(chebyshev(a, b), 5);
+		assert_eq!(neighbors4(a).count(), 4);
+		assert_eq!(neighbors8(a).count(), 8);
+		assert!(neighbors8(a).all(|x| chebyshev(a, x) == 1));
+		assert!(neighbors4(a).eq(Connectivity::Four.neighbors(a)));
+	}
+
+	#[test]
+	fn test_line() {
+		assert_eq!(line(point![2, 2], point![2, 2]).collect::<Vec<_>>(), [point![2, 2]]);
+		for end in [point![5, 2], point![2, 5], point![-5, 2], point![-2, -5], point![5, -5], point![0, -4]] {
+			let start = point![1, 1];
+			let cells: Vec<_> = line(start, end + start).collect();
+			assert_eq!(cells.first(), Some(&start));
+			assert_eq!(cells.last(), Some(&(end + start)));
+			assert_eq!(cells.len() as i32, chebyshev(start, end + start) + 1);
+			assert!(cells.windows(2).all(|x| chebyshev(x[0], x[1]) == 1));
+		}
+	}
+
+	#[test]
+	fn test_circle() {
+		assert_eq!(circle(point![3, 4], 0).collect::<Vec<_>>(), [point![3, 4]]);
+		assert_eq!(circle(point![3, 4], -1).count(), 0);
+		assert_eq!(circle(point![0, 0], 1).count(), 4);
+
+		for radius in 2..20 {
+			let cells: Vec<_> = circle(point![0, 0], radius).collect();
+			let unique: HashSet<_> = cells.iter().copied().collect();
+			assert_eq!(unique.len(), cells.len());
+			for &cell in &cells {
+				let distance = (cell.map(|x| x as f64)).mag();
+				assert!((distance - radius as f64).abs() < 0.75, "{radius}: {cell}");
+			}
+			// the outline is closed
+			let start = point![radius, 0];
+			assert_eq!(flood_fill(start, Connectivity::Eight, |x| unique.contains(&x)).len(), unique.len());
+			assert!(!unique.contains(&point![0, 0]));
+		}
+	}
+
+	#[test]
+	fn test_flood_fill() {
+		assert_eq!(flood_fill(point![2, 0], Connectivity::Four, open).len(), 0);
+
+		let cells = flood_fill(point![0, 0], Connectivity::Four, open);
+		assert_eq!(cells.len(), 35);
+		assert_eq!(cells.iter().collect::<HashSet<_>>().len(), 35);
+		assert!(!cells.contains(&point![5, 2]));
+		assert_eq!(flood_fill(point![5, 2], Connectivity::Four, open), [point![5, 2]]);
+
+		// the pocket is only connected diagonally
+		assert_eq!(flood_fill(point![5, 2], Connectivity::Eight, open).len(), 36);
+	}
+
+	#[test]
+	fn test_path() {
+		let cost = |_, to| open(to).then_some(1);
+		for connectivity in [Connectivity::Four, Connectivity::Eight] {
+			let a = dijkstra(point![0, 0], point![7, 0], connectivity, cost).unwrap();
+			let b = astar(point![0, 0], point![7, 0], connectivity, cost).unwrap();
+			assert_eq!(a.len(), b.len());
+			for path in [a, b] {
+				assert_eq!(path.first(), Some(&point![0, 0]));
+				assert_eq!(path.last(), Some(&point![7, 0]));
+				assert!(path.iter().all(|&x| open(x)));
+				assert!(path.windows(2).all(|x| connectivity.neighbors(x[0]).any(|y| y == x[1])));
+			}
+		}
+		assert_eq!(astar(point![0, 0], point![7, 0], Connectivity::Four, cost).unwrap().len(), 14);
+		assert_eq!(astar(point![0, 0], point![7, 0], Connectivity::Eight, cost).unwrap().len(), 10);
+
+		assert_eq!(astar(point![0, 0], point![0, 0], Connectivity::Four, cost), Some(vec![point![0, 0]]));
+		assert_eq!(astar(point![0, 0], point![5, 2], Connectivity::Four, cost), None);
+		assert!(astar(point![0, 0], point![5, 2], Connectivity::Eight, cost).is_some());
+		assert_eq!(dijkstra(point![0, 0], point![2, 0], Connectivity::Four, cost), None);
+
+		// expensive cells are avoided
+		let cost = |_, to: Point<i32, 2>| open(to).then_some(if to == point![3, 4] { 100 } else { 1 });
+		let path = dijkstra(point![0, 3], point![7, 5], Connectivity::Four, cost).unwrap();
+		assert!(!path.contains(&point![3, 4]));
+	}
+}
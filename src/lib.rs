@@ -4,8 +4,24 @@
 
 #[cfg(feature = "dag")]
 pub mod dag;
+#[cfg(feature = "digraph")]
+pub mod digraph;
 #[cfg(feature = "point")]
 pub mod point;
+#[cfg(feature = "matrix")]
+pub mod matrix;
+#[cfg(feature = "quat")]
+pub mod quat;
+#[cfg(feature = "curve")]
+pub mod curve;
+#[cfg(feature = "geom")]
+pub mod geom;
+#[cfg(feature = "delaunay")]
+pub mod delaunay;
+#[cfg(feature = "grid")]
+pub mod grid;
+#[cfg(feature = "spatial")]
+pub mod spatial;
 #[cfg(feature = "rng")]
 pub mod rng;
 #[cfg(feature = "math")]
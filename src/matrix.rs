@@ -0,0 +1,608 @@
+/*!
+matrices, to accompany [`Point`].
+
+[`Matrix<T, R, C>`] is a matrix of `R` rows and `C` columns. points are treated as column
+vectors, so transforms are applied as `matrix * point`, and combined right to left.
+
+the 3d transform constructors on [`Matrix4`] are right handed, and the projections map depth
+to `[-1, 1]`, matching OpenGL's conventions. [`Matrix3`] constructors are 2d transforms, in
+homogeneous coordinates.
+
+```
+# use lykoi_data::{point, matrix::Matrix4};
+let model = Matrix4::translation(point![1.0, 2.0, 3.0]) * Matrix4::scale(point![2.0, 2.0, 2.0]);
+
+let p = model.transform_point(point![1.0, 1.0, 1.0]);
+
+assert_eq!(p.unpack(), &[3.0, 4.0, 5.0]);
+```
+*/
+
+use std::{array, fmt::Debug};
+
+use crate::point::Point;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Matrix<T, const R: usize, const C: usize>
+where T: Copy + Debug {
+	// row major
+	data: [[T; C]; R],
+}
+
+pub type Matrix2<T> = Matrix<T, 2, 2>;
+pub type Matrix3<T> = Matrix<T, 3, 3>;
+pub type Matrix4<T> = Matrix<T, 4, 4>;
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C>
+where T: Copy + Debug {
+	/// constructs a matrix from an array of rows.
+	#[inline]
+	pub fn new(rows: [[T; C]; R]) -> Self {
+		Self {
+			data: rows,
+		}
+	}
+
+	/// constructs a matrix from an array of columns.
+	#[inline]
+	pub fn from_cols(cols: [[T; R]; C]) -> Self {
+		Self::new(array::from_fn(|r| array::from_fn(|c| cols[c][r])))
+	}
+
+	#[inline]
+	pub fn get(&self, row: usize, col: usize) -> Option<T> {
+		self.data.get(row)?.get(col).cloned()
+	}
+	#[inline]
+	pub fn set(&mut self, row: usize, col: usize, value: T) {
+		if let Some(x) = self.data.get_mut(row).and_then(|x| x.get_mut(col)) {
+			*x = value;
+		}
+	}
+
+	/// returns a row as a [`Point`]. panics if `index` is out of bounds.
+	#[inline]
+	pub fn row(&self, index: usize) -> Point<T, C> {
+		Point::new(self.data[index])
+	}
+	/// returns a column as a [`Point`]. panics if `index` is out of bounds.
+	#[inline]
+	pub fn col(&self, index: usize) -> Point<T, R> {
+		Point::new(array::from_fn(|r| self.data[r][index]))
+	}
+
+	#[inline]
+	pub fn unpack(&self) -> &[[T; C]; R] {
+		&self.data
+	}
+	#[inline]
+	pub fn unpack_mut(&mut self) -> &mut [[T; C]; R] {
+		&mut self.data
+	}
+	#[inline]
+	pub fn unwrap(self) -> [[T; C]; R] {
+		self.data
+	}
+
+	#[inline]
+	pub fn transpose(self) -> Matrix<T, C, R> {
+		Matrix::new(array::from_fn(|c| array::from_fn(|r| self.data[r][c])))
+	}
+}
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C>
+where T: Copy + Debug + num_traits::Num {
+	/// constructs a matrix where every element is zero.
+	#[inline]
+	pub fn zero() -> Self {
+		Self::new([[T::zero(); C]; R])
+	}
+
+	// not pub as the ops traits expose these instead
+	#[inline]
+	fn mul_matrix<const K: usize>(self, other: Matrix<T, C, K>) -> Matrix<T, R, K> {
+		Matrix::new(array::from_fn(|r| array::from_fn(|k| {
+			(0..C).fold(T::zero(), |acc, c| acc + self.data[r][c] * other.data[c][k])
+		})))
+	}
+	#[inline]
+	fn mul_point(self, other: Point<T, C>) -> Point<T, R> {
+		let p = other.unwrap();
+		Point::new(array::from_fn(|r| {
+			(0..C).fold(T::zero(), |acc, c| acc + self.data[r][c] * p[c])
+		}))
+	}
+	#[inline]
+	fn binary(self, other: Self, op: impl Fn(T, T) -> T) -> Self {
+		Self::new(array::from_fn(|r| array::from_fn(|c| op(self.data[r][c], other.data[r][c]))))
+	}
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where T: Copy + Debug + num_traits::Num {
+	/// constructs the identity matrix.
+	#[inline]
+	pub fn identity() -> Self {
+		Self::new(array::from_fn(|r| array::from_fn(|c| if r == c { T::one() } else { T::zero() })))
+	}
+}
+
+impl<T> Matrix<T, 2, 2>
+where T: Copy + Debug + num_traits::Num {
+	#[inline]
+	pub fn determinant(&self) -> T {
+		let [[a, b], [c, d]] = self.data;
+		a * d - b * c
+	}
+
+	/// returns the inverse of the matrix, or `None` if it isn't invertible.
+	pub fn inverse(&self) -> Option<Self> {
+		let det = self.determinant();
+		if det == T::zero() {
+			return None;
+		}
+		let [[a, b], [c, d]] = self.data;
+		let zero = T::zero();
+		Some(Self::new([
+			[d / det, (zero - b) / det],
+			[(zero - c) / det, a / det],
+		]))
+	}
+}
+
+impl<T> Matrix<T, 3, 3>
+where T: Copy + Debug + num_traits::Num {
+	#[inline]
+	pub fn determinant(&self) -> T {
+		let [[a00, a01, a02], [a10, a11, a12], [a20, a21, a22]] = self.data;
+		a00 * (a11 * a22 - a12 * a21)
+			- a01 * (a10 * a22 - a12 * a20)
+			+ a02 * (a10 * a21 - a11 * a20)
+	}
+
+	/// returns the inverse of the matrix, or `None` if it isn't invertible.
+	pub fn inverse(&self) -> Option<Self> {
+		let det = self.determinant();
+		if det == T::zero() {
+			return None;
+		}
+		let [[a00, a01, a02], [a10, a11, a12], [a20, a21, a22]] = self.data;
+		Some(Self::new([
+			[a11 * a22 - a12 * a21, a02 * a21 - a01 * a22, a01 * a12 - a02 * a11],
+			[a12 * a20 - a10 * a22, a00 * a22 - a02 * a20, a02 * a10 - a00 * a12],
+			[a10 * a21 - a11 * a20, a01 * a20 - a00 * a21, a00 * a11 - a01 * a10],
+		].map(|row| row.map(|x| x / det))))
+	}
+}
+
+impl<T> Matrix<T, 4, 4>
+where T: Copy + Debug + num_traits::Num {
+	// 2x2 sub-determinants of the top two rows and bottom two rows, shared by
+	// `determinant()` and `inverse()`
+	#[inline]
+	fn sub_determinants(&self) -> ([T; 6], [T; 6]) {
+		let [
+			[a00, a01, a02, a03],
+			[a10, a11, a12, a13],
+			[a20, a21, a22, a23],
+			[a30, a31, a32, a33],
+		] = self.data;
+		(
+			[
+				a00 * a11 - a10 * a01,
+				a00 * a12 - a10 * a02,
+				a00 * a13 - a10 * a03,
+				a01 * a12 - a11 * a02,
+				a01 * a13 - a11 * a03,
+				a02 * a13 - a12 * a03,
+			],
+			[
+				a20 * a31 - a30 * a21,
+				a20 * a32 - a30 * a22,
+				a20 * a33 - a30 * a23,
+				a21 * a32 - a31 * a22,
+				a21 * a33 - a31 * a23,
+				a22 * a33 - a32 * a23,
+			],
+		)
+	}
+
+	#[inline]
+	pub fn determinant(&self) -> T {
+		let ([s0, s1, s2, s3, s4, s5], [c0, c1, c2, c3, c4, c5]) = self.sub_determinants();
+		s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0
+	}
+
+	/// returns the inverse of the matrix, or `None` if it isn't invertible.
+	pub fn inverse(&self) -> Option<Self> {
+		let ([s0, s1, s2, s3, s4, s5], [c0, c1, c2, c3, c4, c5]) = self.sub_determinants();
+		let det = s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0;
+		if det == T::zero() {
+			return None;
+		}
+		let [
+			[a00, a01, a02, a03],
+			[a10, a11, a12, a13],
+			[a20, a21, a22, a23],
+			[a30, a31, a32, a33],
+		] = self.data;
+		Some(Self::new([
+			[
+				a11 * c5 - a12 * c4 + a13 * c3,
+				a02 * c4 - a01 * c5 - a03 * c3,
+				a31 * s5 - a32 * s4 + a33 * s3,
+				a22 * s4 - a21 * s5 - a23 * s3,
+			],
+			[
+				a12 * c2 - a10 * c5 - a13 * c1,
+				a00 * c5 - a02 * c2 + a03 * c1,
+				a32 * s2 - a30 * s5 - a33 * s1,
+				a20 * s5 - a22 * s2 + a23 * s1,
+			],
+			[
+				a10 * c4 - a11 * c2 + a13 * c0,
+				a01 * c2 - a00 * c4 - a03 * c0,
+				a30 * s4 - a31 * s2 + a33 * s0,
+				a21 * s2 - a20 * s4 - a23 * s0,
+			],
+			[
+				a11 * c1 - a10 * c3 - a12 * c0,
+				a00 * c3 - a01 * c1 + a02 * c0,
+				a31 * s1 - a30 * s3 - a32 * s0,
+				a20 * s3 - a21 * s1 + a22 * s0,
+			],
+		].map(|row| row.map(|x| x / det))))
+	}
+
+	/// transforms a position: the point is extended with `w = 1`, and the result divided by `w`.
+	#[inline]
+	pub fn transform_point(&self, point: Point<T, 3>) -> Point<T, 3> {
+		let [x, y, z, w] = self.mul_point(point.extend(T::one())).unwrap();
+		Point::new([x / w, y / w, z / w])
+	}
+	/// transforms a direction: the point is extended with `w = 0`, so translation is ignored.
+	#[inline]
+	pub fn transform_vector(&self, vector: Point<T, 3>) -> Point<T, 3> {
+		self.mul_point(vector.extend(T::zero())).truncate()
+	}
+}
+
+impl<T> Matrix<T, 2, 2>
+where T: Copy + Debug + num_traits::Float {
+	/// a counter-clockwise rotation by `angle` radians.
+	#[inline]
+	pub fn rotation(angle: T) -> Self {
+		let (s, c) = angle.sin_cos();
+		Self::new([
+			[c, -s],
+			[s, c],
+		])
+	}
+
+	#[inline]
+	pub fn scale(scale: Point<T, 2>) -> Self {
+		let zero = T::zero();
+		Self::new([
+			[scale.x(), zero],
+			[zero, scale.y()],
+		])
+	}
+}
+
+impl<T> Matrix<T, 3, 3>
+where T: Copy + Debug + num_traits::Float {
+	/// a 2d translation, in homogeneous coordinates.
+	#[inline]
+	pub fn translation(offset: Point<T, 2>) -> Self {
+		let (zero, one) = (T::zero(), T::one());
+		Self::new([
+			[one, zero, offset.x()],
+			[zero, one, offset.y()],
+			[zero, zero, one],
+		])
+	}
+
+	/// a 2d counter-clockwise rotation by `angle` radians, in homogeneous coordinates.
+	#[inline]
+	pub fn rotation(angle: T) -> Self {
+		let (zero, one) = (T::zero(), T::one());
+		let (s, c) = angle.sin_cos();
+		Self::new([
+			[c, -s, zero],
+			[s, c, zero],
+			[zero, zero, one],
+		])
+	}
+
+	/// a 2d scale, in homogeneous coordinates.
+	#[inline]
+	pub fn scale(scale: Point<T, 2>) -> Self {
+		let (zero, one) = (T::zero(), T::one());
+		Self::new([
+			[scale.x(), zero, zero],
+			[zero, scale.y(), zero],
+			[zero, zero, one],
+		])
+	}
+}
+
+impl<T> Matrix<T, 4, 4>
+where T: Copy + Debug + num_traits::Float {
+	#[inline]
+	pub fn translation(offset: Point<T, 3>) -> Self {
+		let (zero, one) = (T::zero(), T::one());
+		Self::new([
+			[one, zero, zero, offset.x()],
+			[zero, one, zero, offset.y()],
+			[zero, zero, one, offset.z()],
+			[zero, zero, zero, one],
+		])
+	}
+
+	#[inline]
+	pub fn scale(scale: Point<T, 3>) -> Self {
+		let (zero, one) = (T::zero(), T::one());
+		Self::new([
+			[scale.x(), zero, zero, zero],
+			[zero, scale.y(), zero, zero],
+			[zero, zero, scale.z(), zero],
+			[zero, zero, zero, one],
+		])
+	}
+
+	/// a rotation of `angle` radians around the x axis.
+	#[inline]
+	pub fn rotation_x(angle: T) -> Self {
+		let (zero, one) = (T::zero(), T::one());
+		let (s, c) = angle.sin_cos();
+		Self::new([
+			[one, zero, zero, zero],
+			[zero, c, -s, zero],
+			[zero, s, c, zero],
+			[zero, zero, zero, one],
+		])
+	}
+	/// a rotation of `angle` radians around the y axis.
+	#[inline]
+	pub fn rotation_y(angle: T) -> Self {
+		let (zero, one) = (T::zero(), T::one());
+		let (s, c) = angle.sin_cos();
+		Self::new([
+			[c, zero, s, zero],
+			[zero, one, zero, zero],
+			[-s, zero, c, zero],
+			[zero, zero, zero, one],
+		])
+	}
+	/// a rotation of `angle` radians around the z axis.
+	#[inline]
+	pub fn rotation_z(angle: T) -> Self {
+		let (zero, one) = (T::zero(), T::one());
+		let (s, c) = angle.sin_cos();
+		Self::new([
+			[c, -s, zero, zero],
+			[s, c, zero, zero],
+			[zero, zero, one, zero],
+			[zero, zero, zero, one],
+		])
+	}
+	/// a rotation of `angle` radians around `axis`. `axis` is expected to be normalized.
+	pub fn rotation(axis: Point<T, 3>, angle: T) -> Self {
+		let (zero, one) = (T::zero(), T::one());
+		let (s, c) = angle.sin_cos();
+		let t = one - c;
+		let [x, y, z] = axis.unwrap();
+		Self::new([
+			[t * x * x + c, t * x * y - s * z, t * x * z + s * y, zero],
+			[t * x * y + s * z, t * y * y + c, t * y * z - s * x, zero],
+			[t * x * z - s * y, t * y * z + s * x, t * z * z + c, zero],
+			[zero, zero, zero, one],
+		])
+	}
+
+	/// a view matrix for a camera at `eye` looking at `target`, like `gluLookAt`.
+	pub fn look_at(eye: Point<T, 3>, target: Point<T, 3>, up: Point<T, 3>) -> Self {
+		let (zero, one) = (T::zero(), T::one());
+		let f = (target - eye).normalize();
+		let s = f.cross(up).normalize();
+		let u = s.cross(f);
+		Self::new([
+			[s.x(), s.y(), s.z(), -s.dot(eye)],
+			[u.x(), u.y(), u.z(), -u.dot(eye)],
+			[-f.x(), -f.y(), -f.z(), f.dot(eye)],
+			[zero, zero, zero, one],
+		])
+	}
+
+	/// a perspective projection, like `gluPerspective`. `fov_y` is in radians.
+	pub fn perspective(fov_y: T, aspect: T, near: T, far: T) -> Self {
+		let (zero, one) = (T::zero(), T::one());
+		let two = one + one;
+		let f = one / (fov_y / two).tan();
+		Self::new([
+			[f / aspect, zero, zero, zero],
+			[zero, f, zero, zero],
+			[zero, zero, (far + near) / (near - far), two * far * near / (near - far)],
+			[zero, zero, -one, zero],
+		])
+	}
+
+	/// an orthographic projection, like `glOrtho`.
+	pub fn orthographic(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+		let (zero, one) = (T::zero(), T::one());
+		let two = one + one;
+		Self::new([
+			[two / (right - left), zero, zero, -(right + left) / (right - left)],
+			[zero, two / (top - bottom), zero, -(top + bottom) / (top - bottom)],
+			[zero, zero, -two / (far - near), -(far + near) / (far - near)],
+			[zero, zero, zero, one],
+		])
+	}
+}
+
+impl<T, const R: usize, const C: usize, const K: usize> std::ops::Mul<Matrix<T, C, K>> for Matrix<T, R, C>
+where T: Copy + Debug + num_traits::Num {
+	type Output = Matrix<T, R, K>;
+	#[inline]
+	fn mul(self, rhs: Matrix<T, C, K>) -> Self::Output {
+		self.mul_matrix(rhs)
+	}
+}
+impl<T, const N: usize> std::ops::MulAssign for Matrix<T, N, N>
+where T: Copy + Debug + num_traits::Num {
+	#[inline]
+	fn mul_assign(&mut self, rhs: Self) {
+		*self = self.mul_matrix(rhs)
+	}
+}
+
+impl<T, const R: usize, const C: usize> std::ops::Mul<Point<T, C>> for Matrix<T, R, C>
+where T: Copy + Debug + num_traits::Num {
+	type Output = Point<T, R>;
+	#[inline]
+	fn mul(self, rhs: Point<T, C>) -> Self::Output {
+		self.mul_point(rhs)
+	}
+}
+
+impl<T, const R: usize, const C: usize> std::ops::Mul<T> for Matrix<T, R, C>
+where T: Copy + Debug + num_traits::Num {
+	type Output = Matrix<T, R, C>;
+	#[inline]
+	fn mul(self, rhs: T) -> Self::Output {
+		Self::new(self.data.map(|row| row.map(|x| x * rhs)))
+	}
+}
+
+impl<T, const R: usize, const C: usize> std::ops::Add for Matrix<T, R, C>
+where T: Copy + Debug + num_traits::Num {
+	type Output = Matrix<T, R, C>;
+	#[inline]
+	fn add(self, rhs: Self) -> Self::Output {
+		self.binary(rhs, |x, y| x + y)
+	}
+}
+impl<T, const R: usize, const C: usize> std::ops::AddAssign for Matrix<T, R, C>
+where T: Copy + Debug + num_traits::Num {
+	#[inline]
+	fn add_assign(&mut self, rhs: Self) {
+		*self = self.binary(rhs, |x, y| x + y)
+	}
+}
+
+impl<T, const R: usize, const C: usize> std::ops::Sub for Matrix<T, R, C>
+where T: Copy + Debug + num_traits::Num {
+	type Output = Matrix<T, R, C>;
+	#[inline]
+	fn sub(self, rhs: Self) -> Self::Output {
+		self.binary(rhs, |x, y| x - y)
+	}
+}
+impl<T, const R: usize, const C: usize> std::ops::SubAssign for Matrix<T, R, C>
+where T: Copy + Debug + num_traits::Num {
+	#[inline]
+	fn sub_assign(&mut self, rhs: Self) {
+		*self = self.binary(rhs, |x, y| x - y)
+	}
+}
+
+impl<T, const R: usize, const C: usize> std::ops::Index<(usize, usize)> for Matrix<T, R, C>
+where T: Copy + Debug {
+	type Output = T;
+	#[inline]
+	fn index(&self, index: (usize, usize)) -> &Self::Output {
+		&self.data[index.0][index.1]
+	}
+}
+impl<T, const R: usize, const C: usize> std::ops::IndexMut<(usize, usize)> for Matrix<T, R, C>
+where T: Copy + Debug {
+	#[inline]
+	fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+		&mut self.data[index.0][index.1]
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::point;
+	use super::*;
+
+	fn approx<const R: usize, const C: usize>(a: Matrix<f64, R, C>, b: Matrix<f64, R, C>) -> bool {
+		a.unwrap().iter().flatten().zip(b.unwrap().iter().flatten()).all(|(x, y)| (x - y).abs() < 1e-9)
+	}
+
+	#[test]
+	fn test_main() {
+		let a = Matrix::new([
+			[1, 2, 3],
+			[4, 5, 6],
+		]);
+		let b = Matrix::new([
+			[7, 8],
+			[9, 10],
+			[11, 12],
+		]);
+
+		assert_eq!((a * b).unpack(), &[[58, 64], [139, 154]]);
+		assert_eq!(a.transpose().unpack(), &[[1, 4], [2, 5], [3, 6]]);
+		assert_eq!((a * point![1, 0, -1]).unpack(), &[-2, -2]);
+		assert_eq!((Matrix3::identity() * b).unpack(), b.unpack());
+
+		assert_eq!(Matrix::new([[3, 8], [4, 6]]).determinant(), -14);
+		assert_eq!(Matrix::new([[6, 1, 1], [4, -2, 5], [2, 8, 7]]).determinant(), -306);
+	}
+
+	#[test]
+	fn test_inverse() {
+		let m = Matrix::new([
+			[2.0f64, 0.0, 1.0, 3.0],
+			[1.0, 1.0, 0.0, 2.0],
+			[0.0, 4.0, 1.0, 1.0],
+			[3.0, 1.0, 2.0, 0.0],
+		]);
+		assert!((m.determinant() - m.transpose().determinant()).abs() < 1e-9);
+		assert!(approx(m * m.inverse().unwrap(), Matrix4::identity()));
+
+		let m = Matrix::new([[6.0, 1.0, 1.0], [4.0, -2.0, 5.0], [2.0, 8.0, 7.0]]);
+		assert!(approx(m.inverse().unwrap() * m, Matrix3::identity()));
+
+		let m = Matrix::new([[4.0, 7.0], [2.0, 6.0]]);
+		assert!(approx(m * m.inverse().unwrap(), Matrix2::identity()));
+
+		assert!(Matrix::new([[1.0, 2.0], [2.0, 4.0]]).inverse().is_none());
+	}
+
+	#[test]
+	fn test_transform() {
+		use std::f64::consts::FRAC_PI_2;
+
+		let p = Matrix4::rotation_z(FRAC_PI_2).transform_point(point![1.0, 0.0, 0.0]);
+		assert!((p - point![0.0, 1.0, 0.0]).mag() < 1e-9);
+
+		let r = Matrix4::rotation(point![0.0, 0.0, 1.0], FRAC_PI_2);
+		assert!(approx(r, Matrix4::rotation_z(FRAC_PI_2)));
+
+		let p = Matrix3::translation(point![1.0, 2.0]) * point![1.0, 1.0, 1.0];
+		assert_eq!(p.unpack(), &[2.0, 3.0, 1.0]);
+
+		// the camera sits at +z looking at the origin, so the origin ends up 5 units in front of it
+		let view = Matrix4::look_at(point![0.0, 0.0, 5.0], point![0.0, 0.0, 0.0], point![0.0, 1.0, 0.0]);
+		assert!((view.transform_point(point![0.0, 0.0, 0.0]) - point![0.0, 0.0, -5.0]).mag() < 1e-9);
+		assert!((view.transform_point(point![1.0, 0.0, 0.0]) - point![1.0, 0.0, -5.0]).mag() < 1e-9);
+		assert!((view.transform_point(point![0.0, 1.0, 0.0]) - point![0.0, 1.0, -5.0]).mag() < 1e-9);
+
+		// looking down +x from the origin, +z is to the camera's right
+		let view = Matrix4::look_at(point![0.0, 0.0, 0.0], point![1.0, 0.0, 0.0], point![0.0, 1.0, 0.0]);
+		assert!((view.transform_point(point![0.0, 0.0, 1.0]) - point![1.0, 0.0, 0.0]).mag() < 1e-9);
+		assert!((view.transform_point(point![2.0, 0.0, 0.0]) - point![0.0, 0.0, -2.0]).mag() < 1e-9);
+
+		// points on the near and far planes map to -1 and 1
+		let proj = Matrix4::perspective(FRAC_PI_2, 1.0, 1.0, 10.0);
+		assert!((proj.transform_point(point![0.0, 0.0, -1.0]).z() + 1.0).abs() < 1e-9);
+		assert!((proj.transform_point(point![0.0, 0.0, -10.0]).z() - 1.0).abs() < 1e-9);
+
+		let proj = Matrix4::orthographic(0.0, 10.0, 0.0, 5.0, 0.0, 1.0);
+		assert!((proj.transform_point(point![10.0, 5.0, -1.0]) - point![1.0, 1.0, 1.0]).mag() < 1e-9);
+	}
+}
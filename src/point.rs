@@ -4,7 +4,9 @@ mathematical vectors!
 
 use std::{array, fmt::Debug};
 
-#[derive(Debug, Clone, Copy)]
+// ordering is lexicographic, as derived from the array
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(transparent)]
 pub struct Point<T, const N: usize>
 where T: Copy + Debug {
 	data: [T; N],
@@ -12,53 +14,237 @@ where T: Copy + Debug {
 impl<T, const N: usize> Point<T, N>
 where T: Copy + Debug {
 	#[inline]
-	pub fn new(data: [T; N]) -> Self {
+	pub const fn new(data: [T; N]) -> Self {
 		Self {
 			data,
 		}
 	}
 
 	#[inline]
-	pub fn get(&self, index: usize) -> Option<T> {
-		self.data.get(index).cloned()
+	pub const fn get(&self, index: usize) -> Option<T> {
+		if index < N {
+			Some(self.data[index])
+		} else {
+			None
+		}
 	}
 	#[inline]
-	pub fn set(&mut self, index: usize, value: T) {
-		if let Some(x) = self.data.get_mut(index) {
-			*x = value;
+	pub const fn set(&mut self, index: usize, value: T) {
+		if index < N {
+			self.data[index] = value;
 		}
 	}
 
 	#[inline]
-	pub fn unpack(&self) -> &[T; N] {
+	pub const fn unpack(&self) -> &[T; N] {
 		&self.data
 	}
 	#[inline]
-	pub fn unpack_mut(&mut self) -> &mut [T; N] {
+	pub const fn unpack_mut(&mut self) -> &mut [T; N] {
 		&mut self.data
 	}
 	#[inline]
-	pub fn unwrap(self) -> [T; N] {
+	pub const fn unwrap(self) -> [T; N] {
 		self.data
 	}
 
 	#[inline]
 	pub fn unary(self, op: impl Fn(T) -> T) -> Self {
-		let mut iter = self.data.iter()
-			.map(|x| op(*x));
-		let array: [T; N] = array::from_fn(|_| iter.next().unwrap());
-		Point::new(array)
+		self.map(op)
 	}
 	#[inline]
 	pub fn binary(self, other: Point<T, N>, op: impl Fn(T, T) -> T) -> Self {
-		let mut iter = self.data.iter()
-			.zip(other.data.iter())
-			.map(|x| op(*x.0, *x.1));
-		let array: [T; N] = array::from_fn(|_| iter.next().unwrap());
-		Point::new(array)
+		self.zip_with(other, op)
+	}
+
+	/**
+	applies `op` to every component. unlike [`Self::unary()`], the result can be of a different type.
+
+	```
+	# use lykoi_data::point;
+	let p = point![1.5f32, -2.5].map(|x| x as i32);
+	assert_eq!(p, point![1, -2]);
+	```
+	*/
+	#[inline]
+	pub fn map<U>(self, op: impl Fn(T) -> U) -> Point<U, N>
+	where U: Copy + Debug {
+		Point::new(array::from_fn(|i| op(self.data[i])))
+	}
+	/// applies `op` to every pair of components. unlike [`Self::binary()`], the types can differ.
+	#[inline]
+	pub fn zip_with<U, R>(self, other: Point<U, N>, op: impl Fn(T, U) -> R) -> Point<R, N>
+	where U: Copy + Debug, R: Copy + Debug {
+		Point::new(array::from_fn(|i| op(self.data[i], other.data[i])))
 	}
 }
 
+impl<T, const N: usize> Point<T, N>
+where T: Copy + Debug {
+	/**
+	builds a new point out of the components at `indices`. panics if an index is out of bounds.
+
+	this is the general form of the named swizzles, such as [`Point::xzy()`].
+
+	```
+	# use lykoi_data::point;
+	let p = point![1, 2, 3, 4];
+	assert_eq!(p.swizzle([3, 3, 0, 1]).unpack(), &[4, 4, 1, 2]);
+	```
+	*/
+	#[inline]
+	pub fn swizzle<const M: usize>(self, indices: [usize; M]) -> Point<T, M> {
+		Point::new(indices.map(|i| self.data[i]))
+	}
+}
+
+macro_rules! impl_component {
+	($n:literal; $($get:ident, $set:ident => $i:literal),+) => {
+		impl<T> Point<T, $n>
+		where T: Copy + Debug {
+			$(
+				#[inline]
+				pub const fn $get(&self) -> T {
+					self.data[$i]
+				}
+				#[inline]
+				pub const fn $set(&mut self, value: T) {
+					self.data[$i] = value;
+				}
+			)+
+		}
+	};
+}
+
+impl_component!(1; x, set_x => 0);
+impl_component!(2; x, set_x => 0, y, set_y => 1);
+impl_component!(3; x, set_x => 0, y, set_y => 1, z, set_z => 2);
+impl_component!(4; x, set_x => 0, y, set_y => 1, z, set_z => 2, w, set_w => 3);
+
+// glsl style swizzles. only 2 and 3 component swizzles are named, see `Point::swizzle()` for the rest.
+macro_rules! impl_swizzle {
+	($n:literal; $($name:ident => [$($i:literal),+]),+ $(,)?) => {
+		impl<T> Point<T, $n>
+		where T: Copy + Debug {
+			$(
+				#[inline]
+				pub const fn $name(self) -> Point<T, { [$($i),+].len() }> {
+					Point::new([$(self.data[$i]),+])
+				}
+			)+
+		}
+	};
+}
+
+impl_swizzle!(2;
+	xx => [0, 0], xy => [0, 1], yx => [1, 0], yy => [1, 1], xxx => [0, 0, 0], xxy => [0, 0, 1],
+	xyx => [0, 1, 0], xyy => [0, 1, 1], yxx => [1, 0, 0], yxy => [1, 0, 1], yyx => [1, 1, 0], yyy => [1, 1, 1],
+);
+impl_swizzle!(3;
+	xx => [0, 0], xy => [0, 1], xz => [0, 2], yx => [1, 0], yy => [1, 1], yz => [1, 2],
+	zx => [2, 0], zy => [2, 1], zz => [2, 2], xxx => [0, 0, 0], xxy => [0, 0, 1], xxz => [0, 0, 2],
+	xyx => [0, 1, 0], xyy => [0, 1, 1], xyz => [0, 1, 2], xzx => [0, 2, 0], xzy => [0, 2, 1], xzz => [0, 2, 2],
+	yxx => [1, 0, 0], yxy => [1, 0, 1], yxz => [1, 0, 2], yyx => [1, 1, 0], yyy => [1, 1, 1], yyz => [1, 1, 2],
+	yzx => [1, 2, 0], yzy => [1, 2, 1], yzz => [1, 2, 2], zxx => [2, 0, 0], zxy => [2, 0, 1], zxz => [2, 0, 2],
+	zyx => [2, 1, 0], zyy => [2, 1, 1], zyz => [2, 1, 2], zzx => [2, 2, 0], zzy => [2, 2, 1], zzz => [2, 2, 2],
+);
+impl_swizzle!(4;
+	xx => [0, 0], xy => [0, 1], xz => [0, 2], xw => [0, 3], yx => [1, 0], yy => [1, 1],
+	yz => [1, 2], yw => [1, 3], zx => [2, 0], zy => [2, 1], zz => [2, 2], zw => [2, 3],
+	wx => [3, 0], wy => [3, 1], wz => [3, 2], ww => [3, 3], xxx => [0, 0, 0], xxy => [0, 0, 1],
+	xxz => [0, 0, 2], xxw => [0, 0, 3], xyx => [0, 1, 0], xyy => [0, 1, 1], xyz => [0, 1, 2], xyw => [0, 1, 3],
+	xzx => [0, 2, 0], xzy => [0, 2, 1], xzz => [0, 2, 2], xzw => [0, 2, 3], xwx => [0, 3, 0], xwy => [0, 3, 1],
+	xwz => [0, 3, 2], xww => [0, 3, 3], yxx => [1, 0, 0], yxy => [1, 0, 1], yxz => [1, 0, 2], yxw => [1, 0, 3],
+	yyx => [1, 1, 0], yyy => [1, 1, 1], yyz => [1, 1, 2], yyw => [1, 1, 3], yzx => [1, 2, 0], yzy => [1, 2, 1],
+	yzz => [1, 2, 2], yzw => [1, 2, 3], ywx => [1, 3, 0], ywy => [1, 3, 1], ywz => [1, 3, 2], yww => [1, 3, 3],
+	zxx => [2, 0, 0], zxy => [2, 0, 1], zxz => [2, 0, 2], zxw => [2, 0, 3], zyx => [2, 1, 0], zyy => [2, 1, 1],
+	zyz => [2, 1, 2], zyw => [2, 1, 3], zzx => [2, 2, 0], zzy => [2, 2, 1], zzz => [2, 2, 2], zzw => [2, 2, 3],
+	zwx => [2, 3, 0], zwy => [2, 3, 1], zwz => [2, 3, 2], zww => [2, 3, 3], wxx => [3, 0, 0], wxy => [3, 0, 1],
+	wxz => [3, 0, 2], wxw => [3, 0, 3], wyx => [3, 1, 0], wyy => [3, 1, 1], wyz => [3, 1, 2], wyw => [3, 1, 3],
+	wzx => [3, 2, 0], wzy => [3, 2, 1], wzz => [3, 2, 2], wzw => [3, 2, 3], wwx => [3, 3, 0], wwy => [3, 3, 1],
+	wwz => [3, 3, 2], www => [3, 3, 3],
+);
+
+macro_rules! impl_resize {
+	($n:literal, $m:literal; $($i:literal),*) => {
+		impl<T> Point<T, $n>
+		where T: Copy + Debug {
+			/// returns a point with one more dimension, using `value` as the last component.
+			#[inline]
+			pub const fn extend(self, value: T) -> Point<T, $m> {
+				Point::new([$(self.data[$i],)* value])
+			}
+		}
+		impl<T> Point<T, $m>
+		where T: Copy + Debug {
+			/// returns a point with one less dimension, discarding the last component.
+			#[inline]
+			pub const fn truncate(self) -> Point<T, $n> {
+				Point::new([$(self.data[$i]),*])
+			}
+		}
+	};
+}
+
+impl_resize!(1, 2; 0);
+impl_resize!(2, 3; 0, 1);
+impl_resize!(3, 4; 0, 1, 2);
+
+impl<T, const N: usize> std::ops::Index<usize> for Point<T, N>
+where T: Copy + Debug {
+	type Output = T;
+	#[inline]
+	fn index(&self, index: usize) -> &Self::Output {
+		&self.data[index]
+	}
+}
+impl<T, const N: usize> std::ops::IndexMut<usize> for Point<T, N>
+where T: Copy + Debug {
+	#[inline]
+	fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+		&mut self.data[index]
+	}
+}
+
+impl<T, const N: usize> From<[T; N]> for Point<T, N>
+where T: Copy + Debug {
+	#[inline]
+	fn from(value: [T; N]) -> Self {
+		Point::new(value)
+	}
+}
+impl<T, const N: usize> From<Point<T, N>> for [T; N]
+where T: Copy + Debug {
+	#[inline]
+	fn from(value: Point<T, N>) -> Self {
+		value.data
+	}
+}
+
+macro_rules! impl_tuple {
+	($n:literal; $($t:ident $i:tt),+) => {
+		impl<T> From<($($t,)+)> for Point<T, $n>
+		where T: Copy + Debug {
+			#[inline]
+			fn from(value: ($($t,)+)) -> Self {
+				Point::new([$(value.$i),+])
+			}
+		}
+		impl<T> From<Point<T, $n>> for ($($t,)+)
+		where T: Copy + Debug {
+			#[inline]
+			fn from(value: Point<T, $n>) -> Self {
+				($(value.data[$i],)+)
+			}
+		}
+	};
+}
+
+impl_tuple!(1; T 0);
+impl_tuple!(2; T 0, T 1);
+impl_tuple!(3; T 0, T 1, T 2);
+impl_tuple!(4; T 0, T 1, T 2, T 3);
+
 impl<T, const N: usize> Point<T, N>
 where T: Copy + Debug + num_traits::Num {
 	// not pub as the ops traits expose these instead
@@ -85,121 +271,359 @@ where T: Copy + Debug + num_traits::Num {
 
 	#[inline]
 	pub fn dot(self, other: Point<T, N>) -> T {
-		(self * other)
-			.unwrap()
-			.into_iter()
-			.reduce(|acc, x| acc + x)
-			.unwrap() // who the hell is running this on a 0 length vector?
+		let mut acc = T::zero();
+		for i in 0..N {
+			acc = acc + self.data[i] * other.data[i];
+		}
+		acc
 	}
 }
 
-impl<T> Point<T, 3>
-where T: Copy + Debug + num_traits::Float {
+// elementwise comparisons, named apart from the lexicographic `Ord::min`, `Ord::max` and `Ord::clamp`
+impl<T, const N: usize> Point<T, N>
+where T: Copy + Debug + PartialOrd {
+	/// the smaller of each pair of components.
 	#[inline]
-	pub fn mag(self) -> T {
-		(self * self)
-			.unwrap()
-			.into_iter()
-			.reduce(|acc, x| acc + x)
-			.unwrap() // ...
-			.sqrt()
+	pub fn min_elem(self, other: Point<T, N>) -> Self {
+		self.binary(other, |x, y| if y < x { y } else { x })
+	}
+	/// the larger of each pair of components.
+	#[inline]
+	pub fn max_elem(self, other: Point<T, N>) -> Self {
+		self.binary(other, |x, y| if y > x { y } else { x })
+	}
+	/// clamps each component between the components of `low` and `high`.
+	#[inline]
+	pub fn clamp_elem(self, low: Point<T, N>, high: Point<T, N>) -> Self {
+		self.max_elem(low).min_elem(high)
 	}
-}
 
-impl<T> Point<T, 3>
-where T: Copy + Debug + num_traits::Num {
+	/// returns the smallest component, or `None` if `N` is zero.
 	#[inline]
-	pub fn cross(self, other: Point<T, 3>) -> Self {
-		let lhs = self.unwrap();
-		let rhs = other.unwrap();
-		Point::new([
-			lhs[1] * rhs[2] - lhs[2] * rhs[1],
-			lhs[0] * rhs[2] - lhs[2] * rhs[0],
-			lhs[0] * rhs[1] - lhs[1] * rhs[0],
-		])
+	pub fn min_element(self) -> Option<T> {
+		self.argmin().map(|i| self.data[i])
+	}
+	/// returns the largest component, or `None` if `N` is zero.
+	#[inline]
+	pub fn max_element(self) -> Option<T> {
+		self.argmax().map(|i| self.data[i])
+	}
+	/// returns the index of the smallest component. ties return the first index.
+	#[inline]
+	pub fn argmin(self) -> Option<usize> {
+		(0..N).reduce(|acc, i| if self.data[i] < self.data[acc] { i } else { acc })
+	}
+	/// returns the index of the largest component. ties return the first index.
+	#[inline]
+	pub fn argmax(self) -> Option<usize> {
+		(0..N).reduce(|acc, i| if self.data[i] > self.data[acc] { i } else { acc })
 	}
 }
 
-impl<T, const N: usize> std::ops::Add for Point<T, N>
+impl<T, const N: usize> Point<T, N>
 where T: Copy + Debug + num_traits::Num {
-	type Output = Point<T, N>;
+	/// adds every component together.
 	#[inline]
-	fn add(self, rhs: Self) -> Self::Output {
-		Point::add(self, rhs)
+	pub fn sum(self) -> T {
+		self.data.iter().fold(T::zero(), |acc, &x| acc + x)
 	}
-}
-impl<T, const N: usize> std::ops::AddAssign for Point<T, N>
-where T: Copy + Debug + num_traits::Num {
+	/// multiplies every component together.
 	#[inline]
-	fn add_assign(&mut self, rhs: Self) {
-		*self = Point::add(*self, rhs)
+	pub fn product(self) -> T {
+		self.data.iter().fold(T::one(), |acc, &x| acc * x)
 	}
 }
 
-impl<T, const N: usize> std::ops::Sub for Point<T, N>
-where T: Copy + Debug + num_traits::Num {
-	type Output = Point<T, N>;
+impl<T, const N: usize> Point<T, N>
+where T: Copy + Debug + num_traits::Signed {
 	#[inline]
-	fn sub(self, rhs: Self) -> Self::Output {
-		Point::sub(self, rhs)
+	pub fn abs(self) -> Self {
+		self.unary(|x| x.abs())
 	}
-}
-impl<T, const N: usize> std::ops::SubAssign for Point<T, N>
-where T: Copy + Debug + num_traits::Num {
 	#[inline]
-	fn sub_assign(&mut self, rhs: Self) {
-		*self = Point::sub(*self, rhs)
+	pub fn signum(self) -> Self {
+		self.unary(|x| x.signum())
 	}
 }
 
-impl<T, const N: usize> std::ops::Mul for Point<T, N>
-where T: Copy + Debug + num_traits::Num {
-	type Output = Point<T, N>;
+impl<T, const N: usize> Point<T, N>
+where T: Copy + Debug + num_traits::Float {
 	#[inline]
-	fn mul(self, rhs: Self) -> Self::Output {
-		Point::mul(self, rhs)
+	pub fn floor(self) -> Self {
+		self.unary(|x| x.floor())
 	}
-}
-impl<T, const N: usize> std::ops::MulAssign for Point<T, N>
-where T: Copy + Debug + num_traits::Num {
 	#[inline]
-	fn mul_assign(&mut self, rhs: Self) {
-		*self = Point::mul(*self, rhs)
+	pub fn ceil(self) -> Self {
+		self.unary(|x| x.ceil())
+	}
+	#[inline]
+	pub fn round(self) -> Self {
+		self.unary(|x| x.round())
+	}
+	#[inline]
+	pub fn sqrt(self) -> Self {
+		self.unary(|x| x.sqrt())
+	}
+	#[inline]
+	pub fn powf(self, n: T) -> Self {
+		self.unary(|x| x.powf(n))
+	}
+	/// computes `self * a + b` for each component, with only one rounding error.
+	#[inline]
+	pub fn mul_add(self, a: Point<T, N>, b: Point<T, N>) -> Self {
+		Point::new(array::from_fn(|i| self.data[i].mul_add(a.data[i], b.data[i])))
 	}
 }
 
-impl<T, const N: usize> std::ops::Div for Point<T, N>
-where T: Copy + Debug + num_traits::Num {
-	type Output = Point<T, N>;
+impl<T, const N: usize> Point<T, N>
+where T: Copy + Debug + num_traits::Float {
+	/// returns `true` if every component is within `epsilon` of the other's.
 	#[inline]
-	fn div(self, rhs: Self) -> Self::Output {
-		Point::div(self, rhs)
+	pub fn approx_eq(self, other: Point<T, N>, epsilon: T) -> bool {
+		(0..N).all(|i| (self.data[i] - other.data[i]).abs() <= epsilon)
 	}
-}
-impl<T, const N: usize> std::ops::DivAssign for Point<T, N>
-where T: Copy + Debug + num_traits::Num {
+
+	/// returns the squared length of the vector. cheaper than [`Self::mag()`].
+	#[inline]
+	pub fn mag_sq(self) -> T {
+		self.dot(self)
+	}
+	/// returns the length of the vector.
+	#[inline]
+	pub fn mag(self) -> T {
+		self.mag_sq().sqrt()
+	}
+
+	/**
+	returns a vector with the same direction, but a length of 1.
+
+	a zero length vector produces `NaN` components. see [`Self::try_normalize()`].
+	*/
+	#[inline]
+	pub fn normalize(self) -> Self {
+		let mag = self.mag();
+		self.unary(|x| x / mag)
+	}
+	/// returns a vector with the same direction, but a length of 1, or `None` if the length is zero (or not finite).
+	#[inline]
+	pub fn try_normalize(self) -> Option<Self> {
+		let mag = self.mag();
+		if mag == T::zero() || !mag.is_finite() {
+			return None;
+		}
+		Some(self.unary(|x| x / mag))
+	}
+
+	/// returns the squared distance between two points. cheaper than [`Self::distance()`].
+	#[inline]
+	pub fn distance_sq(self, other: Point<T, N>) -> T {
+		(other - self).mag_sq()
+	}
+	/// returns the distance between two points.
 	#[inline]
-	fn div_assign(&mut self, rhs: Self) {
-		*self = Point::div(*self, rhs)
+	pub fn distance(self, other: Point<T, N>) -> T {
+		(other - self).mag()
+	}
+
+	/// linearly interpolates between `self` and `other`. `t` is not clamped.
+	#[inline]
+	pub fn lerp(self, other: Point<T, N>, t: T) -> Self {
+		self.binary(other, |x, y| x + (y - x) * t)
+	}
+
+	/// returns the projection of `self` onto `other`: the part of `self` parallel to `other`.
+	#[inline]
+	pub fn project(self, other: Point<T, N>) -> Self {
+		let scale = self.dot(other) / other.mag_sq();
+		other.unary(|x| x * scale)
+	}
+	/// returns the rejection of `self` from `other`: the part of `self` perpendicular to `other`.
+	#[inline]
+	pub fn reject(self, other: Point<T, N>) -> Self {
+		self - self.project(other)
+	}
+	/// reflects `self` off of a surface with the normal `normal`. `normal` is expected to be normalized.
+	#[inline]
+	pub fn reflect(self, normal: Point<T, N>) -> Self {
+		let scale = (T::one() + T::one()) * self.dot(normal);
+		self - normal.unary(|x| x * scale)
+	}
+
+	/// returns the angle between two vectors, in radians, from `0` to `pi`.
+	#[inline]
+	pub fn angle_between(self, other: Point<T, N>) -> T {
+		let cos = self.dot(other) / (self.mag_sq() * other.mag_sq()).sqrt();
+		// rounding can push `cos` slightly outside of [-1, 1]
+		cos.max(-T::one()).min(T::one()).acos()
+	}
+
+	/// returns a vector with the same direction, with its length clamped to at most `max`.
+	#[inline]
+	pub fn clamp_length(self, max: T) -> Self {
+		let mag_sq = self.mag_sq();
+		if mag_sq <= max * max {
+			return self;
+		}
+		let scale = max / mag_sq.sqrt();
+		self.unary(|x| x * scale)
 	}
 }
 
-impl<T, const N: usize> std::ops::Rem for Point<T, N>
+impl<T> Point<T, 3>
 where T: Copy + Debug + num_traits::Num {
-	type Output = Point<T, N>;
 	#[inline]
-	fn rem(self, rhs: Self) -> Self::Output {
-		Point::rem(self, rhs)
+	pub fn cross(self, other: Point<T, 3>) -> Self {
+		let lhs = self.unwrap();
+		let rhs = other.unwrap();
+		Point::new([
+			lhs[1] * rhs[2] - lhs[2] * rhs[1],
+			lhs[2] * rhs[0] - lhs[0] * rhs[2],
+			lhs[0] * rhs[1] - lhs[1] * rhs[0],
+		])
+	}
+
+	/// the scalar triple product `self · (b × c)`: the signed volume of the parallelepiped the three vectors span.
+	#[inline]
+	pub fn triple(self, b: Point<T, 3>, c: Point<T, 3>) -> T {
+		self.dot(b.cross(c))
 	}
 }
-impl<T, const N: usize> std::ops::RemAssign for Point<T, N>
+
+impl<T> Point<T, 2>
 where T: Copy + Debug + num_traits::Num {
+	/**
+	the perp-dot product, or 2d cross product: the z component of the cross product of
+	`self` and `other` extended into 3d.
+
+	positive if `other` is counter-clockwise from `self`, negative if clockwise, and zero if parallel.
+	*/
+	#[inline]
+	pub fn perp_dot(self, other: Point<T, 2>) -> T {
+		let lhs = self.unwrap();
+		let rhs = other.unwrap();
+		lhs[0] * rhs[1] - lhs[1] * rhs[0]
+	}
+	/// an alias for [`Self::perp_dot()`].
 	#[inline]
-	fn rem_assign(&mut self, rhs: Self) {
-		*self = Point::rem(*self, rhs)
+	pub fn cross2d(self, other: Point<T, 2>) -> T {
+		self.perp_dot(other)
 	}
 }
 
+// every op is implemented for `Point op Point`, `Point op T`, and the `&Point` variants of each.
+macro_rules! impl_op {
+	($op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident) => {
+		impl<T, const N: usize> std::ops::$op for Point<T, N>
+		where T: Copy + Debug + num_traits::Num {
+			type Output = Point<T, N>;
+			#[inline]
+			fn $fn(self, rhs: Self) -> Self::Output {
+				Point::$fn(self, rhs)
+			}
+		}
+		impl<T, const N: usize> std::ops::$op<&Point<T, N>> for Point<T, N>
+		where T: Copy + Debug + num_traits::Num {
+			type Output = Point<T, N>;
+			#[inline]
+			fn $fn(self, rhs: &Point<T, N>) -> Self::Output {
+				Point::$fn(self, *rhs)
+			}
+		}
+		impl<T, const N: usize> std::ops::$op<Point<T, N>> for &Point<T, N>
+		where T: Copy + Debug + num_traits::Num {
+			type Output = Point<T, N>;
+			#[inline]
+			fn $fn(self, rhs: Point<T, N>) -> Self::Output {
+				Point::$fn(*self, rhs)
+			}
+		}
+		impl<T, const N: usize> std::ops::$op<&Point<T, N>> for &Point<T, N>
+		where T: Copy + Debug + num_traits::Num {
+			type Output = Point<T, N>;
+			#[inline]
+			fn $fn(self, rhs: &Point<T, N>) -> Self::Output {
+				Point::$fn(*self, *rhs)
+			}
+		}
+
+		impl<T, const N: usize> std::ops::$op<T> for Point<T, N>
+		where T: Copy + Debug + num_traits::Num {
+			type Output = Point<T, N>;
+			#[inline]
+			fn $fn(self, rhs: T) -> Self::Output {
+				self.unary(|x| std::ops::$op::$fn(x, rhs))
+			}
+		}
+		impl<T, const N: usize> std::ops::$op<T> for &Point<T, N>
+		where T: Copy + Debug + num_traits::Num {
+			type Output = Point<T, N>;
+			#[inline]
+			fn $fn(self, rhs: T) -> Self::Output {
+				self.unary(|x| std::ops::$op::$fn(x, rhs))
+			}
+		}
+
+		impl<T, const N: usize> std::ops::$op_assign for Point<T, N>
+		where T: Copy + Debug + num_traits::Num {
+			#[inline]
+			fn $fn_assign(&mut self, rhs: Self) {
+				*self = Point::$fn(*self, rhs)
+			}
+		}
+		impl<T, const N: usize> std::ops::$op_assign<&Point<T, N>> for Point<T, N>
+		where T: Copy + Debug + num_traits::Num {
+			#[inline]
+			fn $fn_assign(&mut self, rhs: &Point<T, N>) {
+				*self = Point::$fn(*self, *rhs)
+			}
+		}
+		impl<T, const N: usize> std::ops::$op_assign<T> for Point<T, N>
+		where T: Copy + Debug + num_traits::Num {
+			#[inline]
+			fn $fn_assign(&mut self, rhs: T) {
+				*self = self.unary(|x| std::ops::$op::$fn(x, rhs))
+			}
+		}
+	};
+}
+
+impl_op!(Add, add, AddAssign, add_assign);
+impl_op!(Sub, sub, SubAssign, sub_assign);
+impl_op!(Mul, mul, MulAssign, mul_assign);
+impl_op!(Div, div, DivAssign, div_assign);
+impl_op!(Rem, rem, RemAssign, rem_assign);
+
+// `T op Point` can't be implemented generically (the orphan rule), so only primitives get it.
+macro_rules! impl_op_scalar {
+	(@impl $t:ty, $op:ident, $fn:ident) => {
+		impl<const N: usize> std::ops::$op<Point<$t, N>> for $t {
+			type Output = Point<$t, N>;
+			#[inline]
+			fn $fn(self, rhs: Point<$t, N>) -> Self::Output {
+				rhs.unary(|x| std::ops::$op::$fn(self, x))
+			}
+		}
+		impl<const N: usize> std::ops::$op<&Point<$t, N>> for $t {
+			type Output = Point<$t, N>;
+			#[inline]
+			fn $fn(self, rhs: &Point<$t, N>) -> Self::Output {
+				rhs.unary(|x| std::ops::$op::$fn(self, x))
+			}
+		}
+	};
+	($($t:ty),+) => {
+		$(
+			impl_op_scalar!(@impl $t, Add, add);
+			impl_op_scalar!(@impl $t, Sub, sub);
+			impl_op_scalar!(@impl $t, Mul, mul);
+			impl_op_scalar!(@impl $t, Div, div);
+			impl_op_scalar!(@impl $t, Rem, rem);
+		)+
+	};
+}
+
+impl_op_scalar!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
 impl<T, const N: usize> Point<T, N>
 where T: Copy + Debug + std::ops::Neg<Output = T> {
 	#[inline]
@@ -216,6 +640,195 @@ std::ops::Neg<Output = T> {
 		Point::neg(self)
 	}
 }
+impl<T, const N: usize> std::ops::Neg for &Point<T, N>
+where T: Copy + Debug +
+std::ops::Neg<Output = T> {
+	type Output = Point<T, N>;
+	#[inline]
+	fn neg(self) -> Self::Output {
+		Point::neg(*self)
+	}
+}
+
+/*
+trait methods can't be `const` yet, so the elementwise ops are also provided as `const fn`s
+on each primitive. these are written as plain loops over the components, which is all
+`const` allows.
+*/
+macro_rules! impl_const {
+	(@op $t:ty, $name:ident, $op:tt) => {
+		#[inline]
+		pub const fn $name(self, other: Point<$t, N>) -> Self {
+			let mut data = self.data;
+			let mut i = 0;
+			while i < N {
+				data[i] = data[i] $op other.data[i];
+				i += 1;
+			}
+			Point::new(data)
+		}
+	};
+	($($t:ty),+) => {
+		$(
+			impl<const N: usize> Point<$t, N> {
+				impl_const!(@op $t, const_add, +);
+				impl_const!(@op $t, const_sub, -);
+				impl_const!(@op $t, const_mul, *);
+				impl_const!(@op $t, const_div, /);
+				impl_const!(@op $t, const_rem, %);
+
+				/// multiplies every component by `scalar`.
+				#[inline]
+				pub const fn const_scale(self, scalar: $t) -> Self {
+					let mut data = self.data;
+					let mut i = 0;
+					while i < N {
+						data[i] = data[i] * scalar;
+						i += 1;
+					}
+					Point::new(data)
+				}
+
+				#[inline]
+				pub const fn const_dot(self, other: Point<$t, N>) -> $t {
+					let mut acc = 0 as $t;
+					let mut i = 0;
+					while i < N {
+						acc = acc + self.data[i] * other.data[i];
+						i += 1;
+					}
+					acc
+				}
+			}
+		)+
+	};
+}
+
+impl_const!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+impl<T, const N: usize> Default for Point<T, N>
+where T: Copy + Debug + num_traits::Zero {
+	/// a point where every component is zero.
+	fn default() -> Self {
+		Point::new([T::zero(); N])
+	}
+}
+
+impl<T, const N: usize> std::fmt::Display for Point<T, N>
+where T: Copy + Debug + std::fmt::Display {
+	/// formats as `(x, y, z)`. formatting options, like precision, are applied to every component.
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "(")?;
+		for (i, x) in self.data.iter().enumerate() {
+			if i > 0 {
+				write!(f, ", ")?;
+			}
+			std::fmt::Display::fmt(x, f)?;
+		}
+		write!(f, ")")
+	}
+}
+
+impl<T, const N: usize> std::iter::Sum for Point<T, N>
+where T: Copy + Debug + num_traits::Num {
+	fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+		iter.fold(Point::new([T::zero(); N]), |acc, x| acc + x)
+	}
+}
+impl<'a, T, const N: usize> std::iter::Sum<&'a Point<T, N>> for Point<T, N>
+where T: Copy + Debug + num_traits::Num {
+	fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+		iter.fold(Point::new([T::zero(); N]), |acc, x| acc + x)
+	}
+}
+impl<T, const N: usize> std::iter::Product for Point<T, N>
+where T: Copy + Debug + num_traits::Num {
+	fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+		iter.fold(Point::new([T::one(); N]), |acc, x| acc * x)
+	}
+}
+impl<'a, T, const N: usize> std::iter::Product<&'a Point<T, N>> for Point<T, N>
+where T: Copy + Debug + num_traits::Num {
+	fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+		iter.fold(Point::new([T::one(); N]), |acc, x| acc * x)
+	}
+}
+
+// serialized as a plain array. serde only implements arrays up to 32 elements, so this is done by hand.
+#[cfg(feature = "serde")]
+impl<T, const N: usize> serde::Serialize for Point<T, N>
+where T: Copy + Debug + serde::Serialize {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		use serde::ser::SerializeTuple;
+		let mut tuple = serializer.serialize_tuple(N)?;
+		for x in &self.data {
+			tuple.serialize_element(x)?;
+		}
+		tuple.end()
+	}
+}
+#[cfg(feature = "serde")]
+impl<'de, T, const N: usize> serde::Deserialize<'de> for Point<T, N>
+where T: Copy + Debug + serde::Deserialize<'de> {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		struct Visitor<T, const N: usize>(std::marker::PhantomData<T>);
+		impl<'de, T, const N: usize> serde::de::Visitor<'de> for Visitor<T, N>
+		where T: Copy + Debug + serde::Deserialize<'de> {
+			type Value = Point<T, N>;
+			fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+				write!(f, "an array of length {N}")
+			}
+			fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let mut data = Vec::with_capacity(N);
+				for i in 0..N {
+					data.push(seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(i, &self))?);
+				}
+				let data: [T; N] = data
+					.try_into()
+					.map_err(|x: Vec<T>| serde::de::Error::invalid_length(x.len(), &self))?;
+				Ok(Point::new(data))
+			}
+		}
+		deserializer.deserialize_tuple(N, Visitor(std::marker::PhantomData))
+	}
+}
+
+// sound as `Point` is `repr(transparent)` over `[T; N]`
+#[cfg(feature = "bytemuck")]
+unsafe impl<T, const N: usize> bytemuck::Zeroable for Point<T, N>
+where T: Copy + Debug + bytemuck::Zeroable {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T, const N: usize> bytemuck::Pod for Point<T, N>
+where T: Copy + Debug + bytemuck::Pod {}
+
+#[cfg(feature = "mint")]
+macro_rules! impl_mint {
+	($n:literal, $($mint:ident),+) => {
+		$(
+			impl<T> From<mint::$mint<T>> for Point<T, $n>
+			where T: Copy + Debug {
+				#[inline]
+				fn from(value: mint::$mint<T>) -> Self {
+					Point::new(value.into())
+				}
+			}
+			impl<T> From<Point<T, $n>> for mint::$mint<T>
+			where T: Copy + Debug {
+				#[inline]
+				fn from(value: Point<T, $n>) -> Self {
+					value.data.into()
+				}
+			}
+		)+
+	};
+}
+
+#[cfg(feature = "mint")]
+impl_mint!(2, Vector2, Point2);
+#[cfg(feature = "mint")]
+impl_mint!(3, Vector3, Point3);
+#[cfg(feature = "mint")]
+impl_mint!(4, Vector4);
 
 #[macro_export]
 macro_rules! point {
@@ -236,11 +849,203 @@ mod test {
 
 		let mut p2 = p0 + p1;
 		
-		assert_eq!(p2.unpack(), &[3, 5]);
+		assert_eq!(p2, point![3, 5]);
 		
 		p2 -= point![4, 2];
 
-		assert_eq!(p2.unpack(), &[-1, 3]);
+		assert_eq!(p2, point![-1, 3]);
+	}
+
+	#[test]
+	fn test_elementwise() {
+		let p0 = point![1.5, -2.5, 3.0];
+		let p1 = point![2.0, -3.0, 0.0];
+
+		assert_eq!(p0.min_elem(p1), point![1.5, -3.0, 0.0]);
+		assert_eq!(p0.max_elem(p1), point![2.0, -2.5, 3.0]);
+		assert_eq!(p0.clamp_elem(point![0.0, 0.0, 0.0], point![1.0, 1.0, 1.0]), point![1.0, 0.0, 1.0]);
+		assert_eq!(p0.abs(), point![1.5, 2.5, 3.0]);
+		assert_eq!(p0.floor(), point![1.0, -3.0, 3.0]);
+		assert_eq!(p0.ceil(), point![2.0, -2.0, 3.0]);
+		assert_eq!(p0.round(), point![2.0, -3.0, 3.0]);
+		assert_eq!(p1.signum(), point![1.0, -1.0, 1.0]);
+		assert_eq!(point![4.0, 9.0].sqrt(), point![2.0, 3.0]);
+		assert_eq!(point![2.0, 3.0].powf(2.0), point![4.0, 9.0]);
+		assert_eq!(point![1.0, 2.0].mul_add(point![3.0, 4.0], point![5.0, 6.0]), point![8.0, 14.0]);
+
+		assert_eq!(p0.sum(), 2.0);
+		assert_eq!(point![2, 3, 4].product(), 24);
+		assert_eq!(p0.min_element(), Some(-2.5));
+		assert_eq!(p0.max_element(), Some(3.0));
+		assert_eq!(p0.argmax(), Some(2));
+		assert_eq!(point![5, 1, 5].argmax(), Some(0));
+		assert_eq!(point![5, 1, 5].argmin(), Some(1));
+
+		let p2: super::Point<i32, 3> = p0.map(|x| x as i32);
+		assert_eq!(p2, point![1, -2, 3]);
+		assert_eq!(p2.zip_with(point![true, false, true], |x, b| if b { x } else { 0 }), point![1, 0, 3]);
+	}
+
+	#[test]
+	fn test_traits() {
+		use std::collections::HashSet;
+		use super::Point;
+
+		let set = HashSet::from([point![1, 2], point![1, 2], point![2, 1]]);
+		assert_eq!(set.len(), 2);
+
+		let mut points = vec![point![2, 0], point![1, 5], point![1, 2]];
+		points.sort();
+		assert_eq!(points, vec![point![1, 2], point![1, 5], point![2, 0]]);
+		assert!(point![0.0, 1.0] < point![0.5, 0.0]);
+
+		assert_eq!(Point::<i32, 3>::default(), point![0, 0, 0]);
+		assert_eq!(point![1, 2, 3].to_string(), "(1, 2, 3)");
+		assert_eq!(format!("{:.1}", point![1.0, 2.25]), "(1.0, 2.2)");
+
+		assert!(point![0.1 + 0.2, 1.0].approx_eq(point![0.3, 1.0], 1e-9));
+		assert!(!point![0.1, 1.0].approx_eq(point![0.3, 1.0], 1e-9));
+
+		assert_eq!(points.iter().sum::<Point<i32, 2>>(), point![4, 7]);
+		assert_eq!(points.into_iter().product::<Point<i32, 2>>(), point![2, 0]);
+	}
+
+	#[test]
+	fn test_const() {
+		use super::Point;
+
+		const A: Point<f32, 3> = Point::new([1.0, 2.0, 3.0]);
+		const B: Point<f32, 3> = A.const_add(Point::new([1.0, 1.0, 1.0])).const_scale(2.0);
+		const DOT: f32 = A.const_dot(B);
+		const Y: f32 = B.zyx().y();
+
+		assert_eq!(B.unpack(), &[4.0, 6.0, 8.0]);
+		assert_eq!(DOT, 40.0);
+		assert_eq!(Y, 6.0);
+		assert_eq!(A.const_sub(B).unpack(), (A - B).unpack());
+		assert_eq!(point![7i32, 9].const_rem(point![4, 5]).unpack(), &[3, 4]);
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn test_serde() {
+		let p = point![1.5, -2.0, 3.0];
+		let json = serde_json::to_string(&p).unwrap();
+		assert_eq!(json, "[1.5,-2.0,3.0]");
+
+		let q: super::Point<f64, 3> = serde_json::from_str(&json).unwrap();
+		assert_eq!(q.unpack(), p.unpack());
+		assert!(serde_json::from_str::<super::Point<f64, 3>>("[1.0,2.0]").is_err());
+		assert!(serde_json::from_str::<super::Point<f64, 3>>("[1.0,2.0,3.0,4.0]").is_err());
+	}
+
+	#[cfg(feature = "bytemuck")]
+	#[test]
+	fn test_bytemuck() {
+		let points = [point![1.0f32, 2.0], point![3.0, 4.0]];
+		let floats: &[f32] = bytemuck::cast_slice(&points);
+		assert_eq!(floats, &[1.0, 2.0, 3.0, 4.0]);
+	}
+
+	#[cfg(feature = "mint")]
+	#[test]
+	fn test_mint() {
+		let v: mint::Vector3<i32> = point![1, 2, 3].into();
+		assert_eq!((v.x, v.y, v.z), (1, 2, 3));
+		let p: super::Point<i32, 3> = v.into();
+		assert_eq!(p.unpack(), &[1, 2, 3]);
+	}
+
+	#[test]
+	fn test_cross() {
+		let x = point![1, 0, 0];
+		let y = point![0, 1, 0];
+		let z = point![0, 0, 1];
+
+		assert_eq!(x.cross(y).unpack(), z.unpack());
+		assert_eq!(y.cross(z).unpack(), x.unpack());
+		assert_eq!(z.cross(x).unpack(), y.unpack());
+		assert_eq!(x.triple(y, z), 1);
+
+		let vectors = [point![1, 2, 3], point![-4, 0, 5], point![7, -2, 1], point![3, 3, -6]];
+		for a in vectors {
+			for b in vectors {
+				// anticommutative
+				assert_eq!(a.cross(b).unpack(), (-b.cross(a)).unpack());
+				// perpendicular to both inputs
+				assert_eq!(a.dot(a.cross(b)), 0);
+				assert_eq!(b.dot(a.cross(b)), 0);
+				// lagrange's identity
+				let c = a.cross(b);
+				assert_eq!(c.dot(c), a.dot(a) * b.dot(b) - a.dot(b) * a.dot(b));
+			}
+		}
+
+		assert_eq!(point![1, 0].perp_dot(point![0, 1]), 1);
+		assert_eq!(point![0, 1].cross2d(point![1, 0]), -1);
+		assert_eq!(point![2, 4].perp_dot(point![1, 2]), 0);
+	}
+
+	#[test]
+	fn test_component() {
+		let mut p0 = point![1, 2, 3];
+
+		assert_eq!((p0.x(), p0.y(), p0.z()), (1, 2, 3));
+		p0.set_y(5);
+		p0[2] = 7;
+		assert_eq!(p0[1], 5);
+
+		assert_eq!(p0.xzy().unpack(), &[1, 7, 5]);
+		assert_eq!(p0.zx().unpack(), &[7, 1]);
+		assert_eq!(p0.xy().extend(0).unpack(), &[1, 5, 0]);
+		assert_eq!(p0.extend(9).truncate().unpack(), &[1, 5, 7]);
+
+		let p1: super::Point<_, 2> = (4, 6).into();
+		let t: (i32, i32) = p1.into();
+		let a: [i32; 2] = p1.into();
+		assert_eq!(t, (4, 6));
+		assert_eq!(a, [4, 6]);
+	}
+
+	#[test]
+	#[allow(clippy::op_ref, reason = "testing the reference variants")]
+	fn test_scalar() {
+		let p0 = point![1.0f64, 2.0, 4.0];
+
+		assert_eq!((p0 * 2.0).unpack(), &[2.0, 4.0, 8.0]);
+		assert_eq!((2.0 * p0).unpack(), &[2.0, 4.0, 8.0]);
+		assert_eq!((p0 / 2.0).unpack(), &[0.5, 1.0, 2.0]);
+		assert_eq!((4.0 / p0).unpack(), &[4.0, 2.0, 1.0]);
+		assert_eq!((1.0 - &p0).unpack(), &[0.0, -1.0, -3.0]);
+		assert_eq!((&p0 + &p0).unpack(), &[2.0, 4.0, 8.0]);
+		assert_eq!((-&p0).unpack(), &[-1.0, -2.0, -4.0]);
+
+		let mut p1 = point![7, 8];
+		p1 %= 3;
+		assert_eq!(p1.unpack(), &[1, 2]);
+		p1 *= &point![2, 3];
+		assert_eq!(p1.unpack(), &[2, 6]);
+	}
+
+	#[test]
+	fn test_float() {
+		let p0 = point![3.0, 4.0];
+		let p1 = point![0.0, 2.0];
+
+		assert_eq!(p0.mag(), 5.0);
+		assert_eq!(p0.mag_sq(), 25.0);
+		assert_eq!(p0.normalize().unpack(), &[0.6, 0.8]);
+		assert!(point![0.0, 0.0, 0.0].try_normalize().is_none());
+		assert_eq!(p0.distance(p1), 13.0f64.sqrt());
+		assert_eq!(p0.lerp(p1, 0.5).unpack(), &[1.5, 3.0]);
+
+		assert_eq!(p0.project(p1).unpack(), &[0.0, 4.0]);
+		assert_eq!(p0.reject(p1).unpack(), &[3.0, 0.0]);
+		assert_eq!(point![1.0, -1.0].reflect(point![0.0, 1.0]).unpack(), &[1.0, 1.0]);
+		assert!((point![1.0, 0.0, 0.0].angle_between(point![0.0, 0.0, 2.0]) - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+
+		assert_eq!(p0.clamp_length(2.5).unpack(), &[1.5, 2.0]);
+		assert_eq!(p0.clamp_length(10.0).unpack(), &[3.0, 4.0]);
 	}
 }
 
@@ -0,0 +1,333 @@
+/*!
+quaternions, for 3d rotations.
+
+a [`Quat`] represents a rotation without gimbal lock, and can be smoothly interpolated
+with [`Quat::slerp()`]. rotations are combined like matrices: `a * b` applies `b` first,
+then `a`.
+
+```
+# use lykoi_data::{point, quat::Quat};
+use std::f64::consts::FRAC_PI_2;
+
+let q = Quat::from_axis_angle(point![0.0, 0.0, 1.0], FRAC_PI_2);
+
+let p = q.rotate(point![1.0, 0.0, 0.0]);
+
+assert!((p - point![0.0, 1.0, 0.0]).mag() < 1e-9);
+```
+*/
+
+use std::fmt::Debug;
+
+use crate::{matrix::{Matrix3, Matrix4}, point::Point};
+
+/// a quaternion `w + xi + yj + zk`. rotations are expected to be normalized.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quat<T>
+where T: Copy + Debug {
+	pub x: T,
+	pub y: T,
+	pub z: T,
+	pub w: T,
+}
+
+impl<T> Quat<T>
+where T: Copy + Debug + num_traits::Float {
+	#[inline]
+	pub fn new(x: T, y: T, z: T, w: T) -> Self {
+		Self {
+			x,
+			y,
+			z,
+			w,
+		}
+	}
+
+	/// the identity rotation.
+	#[inline]
+	pub fn identity() -> Self {
+		Self::new(T::zero(), T::zero(), T::zero(), T::one())
+	}
+
+	/// a rotation of `angle` radians around `axis`. `axis` is expected to be normalized.
+	#[inline]
+	pub fn from_axis_angle(axis: Point<T, 3>, angle: T) -> Self {
+		let half = angle / (T::one() + T::one());
+		let (s, c) = half.sin_cos();
+		Self::new(axis.x() * s, axis.y() * s, axis.z() * s, c)
+	}
+
+	/**
+	a rotation from euler angles, in radians.
+
+	the rotations are applied in the order `roll` (around x), `pitch` (around y), then `yaw` (around z).
+	*/
+	pub fn from_euler(roll: T, pitch: T, yaw: T) -> Self {
+		let two = T::one() + T::one();
+		let (sr, cr) = (roll / two).sin_cos();
+		let (sp, cp) = (pitch / two).sin_cos();
+		let (sy, cy) = (yaw / two).sin_cos();
+		Self::new(
+			sr * cp * cy - cr * sp * sy,
+			cr * sp * cy + sr * cp * sy,
+			cr * cp * sy - sr * sp * cy,
+			cr * cp * cy + sr * sp * sy,
+		)
+	}
+
+	/// returns the rotation as `(roll, pitch, yaw)` euler angles. the inverse of [`Self::from_euler()`].
+	pub fn to_euler(self) -> (T, T, T) {
+		let (one, two) = (T::one(), T::one() + T::one());
+		let Self { x, y, z, w } = self;
+		let roll = (two * (w * x + y * z)).atan2(one - two * (x * x + y * y));
+		let pitch = (two * (w * y - z * x)).max(-one).min(one).asin();
+		let yaw = (two * (w * z + x * y)).atan2(one - two * (y * y + z * z));
+		(roll, pitch, yaw)
+	}
+
+	/// returns the rotation as a normalized axis, and an angle in radians.
+	pub fn to_axis_angle(self) -> (Point<T, 3>, T) {
+		let q = if self.w < T::zero() { -self } else { self };
+		let s = (T::one() - q.w * q.w).max(T::zero()).sqrt();
+		let angle = (T::one() + T::one()) * q.w.min(T::one()).acos();
+		if s <= T::epsilon() {
+			return (Point::new([T::one(), T::zero(), T::zero()]), angle);
+		}
+		(Point::new([q.x / s, q.y / s, q.z / s]), angle)
+	}
+
+	#[inline]
+	pub fn dot(self, other: Self) -> T {
+		self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+	}
+	#[inline]
+	pub fn mag(self) -> T {
+		self.dot(self).sqrt()
+	}
+	#[inline]
+	pub fn normalize(self) -> Self {
+		let mag = self.mag();
+		Self::new(self.x / mag, self.y / mag, self.z / mag, self.w / mag)
+	}
+
+	#[inline]
+	pub fn conjugate(self) -> Self {
+		Self::new(-self.x, -self.y, -self.z, self.w)
+	}
+	/// returns the inverse. for a normalized quaternion, this is the same as [`Self::conjugate()`].
+	#[inline]
+	pub fn inverse(self) -> Self {
+		let mag_sq = self.dot(self);
+		let c = self.conjugate();
+		Self::new(c.x / mag_sq, c.y / mag_sq, c.z / mag_sq, c.w / mag_sq)
+	}
+
+	/// rotates `point` by this rotation.
+	#[inline]
+	pub fn rotate(self, point: Point<T, 3>) -> Point<T, 3> {
+		self.to_matrix3() * point
+	}
+
+	/// normalized linear interpolation. cheaper than [`Self::slerp()`], but doesn't move at a constant speed.
+	pub fn nlerp(self, other: Self, t: T) -> Self {
+		// take the shortest path
+		let other = if self.dot(other) < T::zero() { -other } else { other };
+		Self::new(
+			self.x + (other.x - self.x) * t,
+			self.y + (other.y - self.y) * t,
+			self.z + (other.z - self.z) * t,
+			self.w + (other.w - self.w) * t,
+		).normalize()
+	}
+
+	/// spherical linear interpolation, rotating at a constant speed along the shortest path.
+	pub fn slerp(self, other: Self, t: T) -> Self {
+		let mut cos = self.dot(other);
+		let other = if cos < T::zero() {
+			cos = -cos;
+			-other
+		} else {
+			other
+		};
+
+		// nearly parallel, where `sin` approaches zero
+		if cos > T::one() - T::epsilon().sqrt() {
+			return self.nlerp(other, t);
+		}
+
+		let angle = cos.acos();
+		let sin = angle.sin();
+		let a = ((T::one() - t) * angle).sin() / sin;
+		let b = (t * angle).sin() / sin;
+		Self::new(
+			self.x * a + other.x * b,
+			self.y * a + other.y * b,
+			self.z * a + other.z * b,
+			self.w * a + other.w * b,
+		)
+	}
+
+	/// converts the rotation into a 3x3 rotation matrix.
+	pub fn to_matrix3(self) -> Matrix3<T> {
+		let (one, two) = (T::one(), T::one() + T::one());
+		let Self { x, y, z, w } = self;
+		Matrix3::new([
+			[one - two * (y * y + z * z), two * (x * y - w * z), two * (x * z + w * y)],
+			[two * (x * y + w * z), one - two * (x * x + z * z), two * (y * z - w * x)],
+			[two * (x * z - w * y), two * (y * z + w * x), one - two * (x * x + y * y)],
+		])
+	}
+
+	/// converts the rotation into a 4x4 homogeneous rotation matrix.
+	pub fn to_matrix4(self) -> Matrix4<T> {
+		let (zero, one) = (T::zero(), T::one());
+		let [r0, r1, r2] = self.to_matrix3().unwrap();
+		Matrix4::new([
+			[r0[0], r0[1], r0[2], zero],
+			[r1[0], r1[1], r1[2], zero],
+			[r2[0], r2[1], r2[2], zero],
+			[zero, zero, zero, one],
+		])
+	}
+
+	/// converts a 3x3 rotation matrix into a quaternion. the matrix is expected to be a pure rotation.
+	pub fn from_matrix3(matrix: Matrix3<T>) -> Self {
+		let (one, two) = (T::one(), T::one() + T::one());
+		let quarter = one / (two * two);
+		let [[m00, m01, m02], [m10, m11, m12], [m20, m21, m22]] = matrix.unwrap();
+		let trace = m00 + m11 + m22;
+
+		// pick the largest component to divide by, for stability
+		if trace > T::zero() {
+			let s = (trace + one).sqrt() * two;
+			Self::new((m21 - m12) / s, (m02 - m20) / s, (m10 - m01) / s, quarter * s)
+		} else if m00 > m11 && m00 > m22 {
+			let s = (one + m00 - m11 - m22).sqrt() * two;
+			Self::new(quarter * s, (m01 + m10) / s, (m02 + m20) / s, (m21 - m12) / s)
+		} else if m11 > m22 {
+			let s = (one + m11 - m00 - m22).sqrt() * two;
+			Self::new((m01 + m10) / s, quarter * s, (m12 + m21) / s, (m02 - m20) / s)
+		} else {
+			let s = (one + m22 - m00 - m11).sqrt() * two;
+			Self::new((m02 + m20) / s, (m12 + m21) / s, quarter * s, (m10 - m01) / s)
+		}
+	}
+
+	/// converts the rotation part of a 4x4 matrix into a quaternion.
+	pub fn from_matrix4(matrix: Matrix4<T>) -> Self {
+		let m = matrix.unwrap();
+		Self::from_matrix3(Matrix3::new([
+			[m[0][0], m[0][1], m[0][2]],
+			[m[1][0], m[1][1], m[1][2]],
+			[m[2][0], m[2][1], m[2][2]],
+		]))
+	}
+}
+
+impl<T> Default for Quat<T>
+where T: Copy + Debug + num_traits::Float {
+	fn default() -> Self {
+		Self::identity()
+	}
+}
+
+impl<T> std::ops::Mul for Quat<T>
+where T: Copy + Debug + num_traits::Float {
+	type Output = Quat<T>;
+	#[inline]
+	fn mul(self, rhs: Self) -> Self::Output {
+		let (a, b) = (self, rhs);
+		Quat::new(
+			a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+			a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+			a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+			a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+		)
+	}
+}
+impl<T> std::ops::MulAssign for Quat<T>
+where T: Copy + Debug + num_traits::Float {
+	#[inline]
+	fn mul_assign(&mut self, rhs: Self) {
+		*self = *self * rhs
+	}
+}
+
+impl<T> std::ops::Mul<Point<T, 3>> for Quat<T>
+where T: Copy + Debug + num_traits::Float {
+	type Output = Point<T, 3>;
+	#[inline]
+	fn mul(self, rhs: Point<T, 3>) -> Self::Output {
+		self.rotate(rhs)
+	}
+}
+
+impl<T> std::ops::Neg for Quat<T>
+where T: Copy + Debug + num_traits::Float {
+	type Output = Quat<T>;
+	#[inline]
+	fn neg(self) -> Self::Output {
+		Quat::new(-self.x, -self.y, -self.z, -self.w)
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+	use crate::{matrix::Matrix4, point};
+	use super::*;
+
+	fn approx(a: Quat<f64>, b: Quat<f64>) -> bool {
+		// `q` and `-q` are the same rotation
+		(a.dot(b).abs() - 1.0).abs() < 1e-9
+	}
+
+	#[test]
+	fn test_main() {
+		let x = Quat::from_axis_angle(point![1.0, 0.0, 0.0], FRAC_PI_2);
+		let z = Quat::from_axis_angle(point![0.0, 0.0, 1.0], FRAC_PI_2);
+
+		// z first, then x
+		let p = (x * z).rotate(point![1.0, 0.0, 0.0]);
+		assert!((p - point![0.0, 0.0, 1.0]).mag() < 1e-9);
+
+		let p = (z * z.inverse()).rotate(point![1.0, 2.0, 3.0]);
+		assert!((p - point![1.0, 2.0, 3.0]).mag() < 1e-9);
+		assert!(approx(z.conjugate(), z.inverse()));
+
+		let q = Quat::from_euler(0.3f64, -0.2, 1.1);
+		let (roll, pitch, yaw) = q.to_euler();
+		assert!((roll - 0.3).abs() < 1e-9 && (pitch + 0.2).abs() < 1e-9 && (yaw - 1.1).abs() < 1e-9);
+
+		let (axis, angle) = z.to_axis_angle();
+		assert!((axis - point![0.0, 0.0, 1.0]).mag() < 1e-9 && (angle - FRAC_PI_2).abs() < 1e-9);
+	}
+
+	#[test]
+	fn test_interpolate() {
+		let a = Quat::identity();
+		let b = Quat::from_axis_angle(point![0.0, 1.0, 0.0], FRAC_PI_2);
+
+		assert!(approx(a.slerp(b, 0.5), Quat::from_axis_angle(point![0.0, 1.0, 0.0], FRAC_PI_4)));
+		assert!(approx(a.slerp(b, 0.0), a));
+		assert!(approx(a.slerp(b, 1.0), b));
+		assert!(approx(a.nlerp(b, 0.5), Quat::from_axis_angle(point![0.0, 1.0, 0.0], FRAC_PI_4)));
+	}
+
+	#[test]
+	fn test_matrix() {
+		let axis = point![1.0, 2.0, 2.0] / 3.0;
+		for angle in [0.1, 1.0, 2.5, PI, 4.0] {
+			let q = Quat::from_axis_angle(axis, angle);
+			let m = Matrix4::rotation(axis, angle);
+
+			let a = q.to_matrix4().unwrap();
+			let b = m.unwrap();
+			assert!(a.iter().flatten().zip(b.iter().flatten()).all(|(x, y)| (x - y).abs() < 1e-9));
+
+			assert!(approx(Quat::from_matrix4(m), q));
+		}
+	}
+}
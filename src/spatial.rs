@@ -0,0 +1,974 @@
+/*!
+spatial indexes over [`Point`], for fast neighbor queries.
+
+- [`HashGrid`] - a uniform grid of buckets, stored in a hash map. best when points are
+  evenly spread, and queries are around the size of a cell.
+- [`Orthtree`] - a [`Quadtree`] in 2d, or an [`Octree`] in 3d. adapts to clustered points.
+- [`KdTree`] - a [k-d tree](https://en.wikipedia.org/wiki/K-d_tree). best for nearest neighbor
+  queries on points that don't move often.
+
+every index implements [`Spatial`], and stores a value `V` alongside each point. inserting
+returns a [`Key`], which is used to look up or remove the point later. queries return keys.
+
+```
+# use lykoi_data::{point, spatial::{Spatial, KdTree}};
+let mut tree = KdTree::new();
+
+let a = tree.insert(point![0.0, 0.0], "a");
+let b = tree.insert(point![5.0, 0.0], "b");
+let c = tree.insert(point![1.0, 1.0], "c");
+
+assert_eq!(tree.nearest(point![0.9, 0.8], 2), vec![c, a]);
+assert_eq!(tree.radius(point![4.0, 0.0], 1.5), vec![b]);
+```
+*/
+
+use std::{cmp::Ordering, collections::{BinaryHeap, HashMap}, fmt::Debug};
+
+use crate::{geom::{Aabb, Sphere}, point::Point};
+
+/**
+identifies a point inside a [`Spatial`] index. obtained from [`Spatial::insert()`].
+
+keys of removed points may be reused by later insertions.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Key(usize);
+
+/// the operations shared by every spatial index.
+pub trait Spatial<T, V, const N: usize>
+where T: Copy + Debug {
+	/// inserts a point, returning a [`Key`] to refer to it.
+	fn insert(&mut self, point: Point<T, N>, value: V) -> Key;
+
+	/// removes a point, returning it and its value, or `None` if `key` doesn't exist.
+	fn remove(&mut self, key: Key) -> Option<(Point<T, N>, V)>;
+
+	/// returns a point and its value, or `None` if `key` doesn't exist.
+	fn get(&self, key: Key) -> Option<(Point<T, N>, &V)>;
+
+	/// returns the amount of points in the index.
+	fn len(&self) -> usize;
+
+	/// returns `true` if the index has no points.
+	fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// returns every point within `radius` of `center`, in no particular order.
+	fn radius(&self, center: Point<T, N>, radius: T) -> Vec<Key>;
+
+	/// returns the `k` points closest to `point`, sorted from nearest to farthest.
+	fn nearest(&self, point: Point<T, N>, k: usize) -> Vec<Key>;
+
+	/// returns every point inside `aabb`, in no particular order.
+	fn range(&self, aabb: &Aabb<T, N>) -> Vec<Key>;
+}
+
+// point storage shared by every index, so keys stay stable as the structure changes
+#[derive(Debug, Clone)]
+struct Slab<T, V, const N: usize>
+where T: Copy + Debug {
+	items: Vec<Option<(Point<T, N>, V)>>,
+	free: Vec<usize>,
+}
+impl<T, V, const N: usize> Slab<T, V, N>
+where T: Copy + Debug {
+	fn new() -> Self {
+		Self {
+			items: Vec::new(),
+			free: Vec::new(),
+		}
+	}
+
+	fn insert(&mut self, point: Point<T, N>, value: V) -> usize {
+		if let Some(i) = self.free.pop() {
+			self.items[i] = Some((point, value));
+			i
+		} else {
+			self.items.push(Some((point, value)));
+			self.items.len() - 1
+		}
+	}
+
+	fn remove(&mut self, index: usize) -> Option<(Point<T, N>, V)> {
+		let item = self.items.get_mut(index)?.take()?;
+		self.free.push(index);
+		Some(item)
+	}
+
+	fn get(&self, index: usize) -> Option<&(Point<T, N>, V)> {
+		self.items.get(index)?.as_ref()
+	}
+
+	fn point(&self, index: usize) -> Point<T, N> {
+		self.items[index].as_ref().unwrap().0
+	}
+
+	fn len(&self) -> usize {
+		self.items.len() - self.free.len()
+	}
+}
+
+// a float ordered by `partial_cmp`, for use in heaps
+#[derive(Debug, Clone, Copy)]
+struct Dist<T>(T, usize);
+impl<T: PartialOrd> PartialEq for Dist<T> {
+	fn eq(&self, other: &Self) -> bool {
+		self.cmp(other) == Ordering::Equal
+	}
+}
+impl<T: PartialOrd> Eq for Dist<T> {}
+impl<T: PartialOrd> PartialOrd for Dist<T> {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+impl<T: PartialOrd> Ord for Dist<T> {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.0.partial_cmp(&other.0).unwrap_or(Ordering::Equal).then(self.1.cmp(&other.1))
+	}
+}
+
+// keeps the `k` closest candidates seen so far
+struct Nearest<T> {
+	k: usize,
+	heap: BinaryHeap<Dist<T>>,
+}
+impl<T: Copy + PartialOrd> Nearest<T> {
+	fn new(k: usize) -> Self {
+		Self {
+			k,
+			heap: BinaryHeap::with_capacity(k + 1),
+		}
+	}
+
+	fn push(&mut self, dist: T, index: usize) {
+		if self.k == 0 {
+			return;
+		}
+		if self.heap.len() < self.k {
+			self.heap.push(Dist(dist, index));
+		} else if self.heap.peek().is_some_and(|x| dist < x.0) {
+			self.heap.pop();
+			self.heap.push(Dist(dist, index));
+		}
+	}
+
+	// the distance a candidate has to beat to be kept
+	fn worst(&self) -> Option<T> {
+		if self.heap.len() < self.k {
+			None
+		} else {
+			self.heap.peek().map(|x| x.0)
+		}
+	}
+
+	fn finish(self) -> Vec<Key> {
+		self.heap.into_sorted_vec().into_iter().map(|x| Key(x.1)).collect()
+	}
+}
+
+/**
+a uniform grid of square cells, each holding the points inside it.
+
+cells are stored in a [`HashMap`], so the grid is unbounded, and empty cells cost nothing.
+queries visit every cell they overlap, so `cell_size` should be around the size of a
+typical query radius. inserting a point that isn't finite panics.
+*/
+#[derive(Debug, Clone)]
+pub struct HashGrid<T, V, const N: usize>
+where T: Copy + Debug {
+	cell_size: T,
+	cells: HashMap<[i64; N], Vec<usize>>,
+	slab: Slab<T, V, N>,
+}
+impl<T, V, const N: usize> HashGrid<T, V, N>
+where T: Copy + Debug + num_traits::Float {
+	/// constructs an empty grid, with cells `cell_size` wide. panics if `cell_size` isn't positive and finite.
+	pub fn new(cell_size: T) -> Self {
+		assert!(cell_size > T::zero() && cell_size.is_finite(), "cell size must be positive and finite");
+		Self {
+			cell_size,
+			cells: HashMap::new(),
+			slab: Slab::new(),
+		}
+	}
+
+	// coordinates past the range of `i64` saturate, so infinite queries still cover every cell
+	fn cell(&self, point: Point<T, N>) -> [i64; N] {
+		point.unwrap().map(|x| {
+			let x = (x / self.cell_size).floor();
+			x.to_i64().unwrap_or(if x > T::zero() { i64::MAX } else { i64::MIN })
+		})
+	}
+
+	// the distance from `point` to the farthest corner of the occupied cells
+	fn reach(&self, point: Point<T, N>) -> T {
+		let mut lo = [i64::MAX; N];
+		let mut hi = [i64::MIN; N];
+		for cell in self.cells.keys() {
+			for i in 0..N {
+				lo[i] = lo[i].min(cell[i]);
+				hi[i] = hi[i].max(cell[i]);
+			}
+		}
+		(0..N)
+			.map(|i| {
+				let lo = T::from(lo[i]).unwrap() * self.cell_size;
+				let hi = (T::from(hi[i]).unwrap() + T::one()) * self.cell_size;
+				let d = (point[i] - lo).abs().max((point[i] - hi).abs());
+				d * d
+			})
+			.fold(T::zero(), |acc, x| acc + x)
+			.sqrt()
+	}
+
+	// calls `f` with every index in every cell overlapping `aabb`
+	fn visit(&self, aabb: &Aabb<T, N>, mut f: impl FnMut(usize)) {
+		let lo = self.cell(aabb.min);
+		let hi = self.cell(aabb.max);
+
+		// with more cells to step through than are occupied, check the occupied ones instead
+		let count = (0..N).fold(1u128, |acc, i| acc.saturating_mul((hi[i] as i128 - lo[i] as i128 + 1) as u128));
+		if count > self.cells.len() as u128 {
+			for (cell, bucket) in &self.cells {
+				if (0..N).all(|i| lo[i] <= cell[i] && cell[i] <= hi[i]) {
+					bucket.iter().for_each(|&i| f(i));
+				}
+			}
+			return;
+		}
+
+		// step through every cell between `lo` and `hi`, like an odometer
+		let mut cell = lo;
+		loop {
+			if let Some(bucket) = self.cells.get(&cell) {
+				bucket.iter().for_each(|&i| f(i));
+			}
+			let mut axis = 0;
+			loop {
+				if axis == N {
+					return;
+				}
+				if cell[axis] < hi[axis] {
+					cell[axis] += 1;
+					break;
+				}
+				cell[axis] = lo[axis];
+				axis += 1;
+			}
+		}
+	}
+}
+impl<T, V, const N: usize> Spatial<T, V, N> for HashGrid<T, V, N>
+where T: Copy + Debug + num_traits::Float {
+	fn insert(&mut self, point: Point<T, N>, value: V) -> Key {
+		assert!(point.unwrap().iter().all(|x| x.is_finite()), "points must be finite");
+		let index = self.slab.insert(point, value);
+		self.cells.entry(self.cell(point)).or_default().push(index);
+		Key(index)
+	}
+
+	fn remove(&mut self, key: Key) -> Option<(Point<T, N>, V)> {
+		let (point, value) = self.slab.remove(key.0)?;
+		let cell = self.cell(point);
+		if let Some(bucket) = self.cells.get_mut(&cell) {
+			bucket.retain(|&x| x != key.0);
+			if bucket.is_empty() {
+				self.cells.remove(&cell);
+			}
+		}
+		Some((point, value))
+	}
+
+	fn get(&self, key: Key) -> Option<(Point<T, N>, &V)> {
+		self.slab.get(key.0).map(|(p, v)| (*p, v))
+	}
+
+	fn len(&self) -> usize {
+		self.slab.len()
+	}
+
+	fn radius(&self, center: Point<T, N>, radius: T) -> Vec<Key> {
+		let sphere = Sphere::new(center, radius);
+		let mut result = Vec::new();
+		self.visit(&sphere.aabb(), |i| {
+			if sphere.contains(self.slab.point(i)) {
+				result.push(Key(i));
+			}
+		});
+		result
+	}
+
+	fn nearest(&self, point: Point<T, N>, k: usize) -> Vec<Key> {
+		let len = self.len();
+		let mut radius = self.cell_size;
+		let mut reach = None;
+		loop {
+			// once `k` points are within `radius`, nothing outside it can be closer
+			let found = self.radius(point, radius);
+			if found.len() >= k.min(len) {
+				let mut nearest = Nearest::new(k);
+				for key in found {
+					nearest.push(self.slab.point(key.0).distance_sq(point), key.0);
+				}
+				return nearest.finish();
+			}
+
+			// growing past the occupied cells won't find anything more, so check every point instead
+			let reach = *reach.get_or_insert_with(|| self.reach(point));
+			if reach.is_nan() || radius >= reach {
+				let mut nearest = Nearest::new(k);
+				for (i, item) in self.slab.items.iter().enumerate() {
+					if let Some((p, _)) = item {
+						nearest.push(p.distance_sq(point), i);
+					}
+				}
+				return nearest.finish();
+			}
+			radius = (radius + radius).min(reach);
+		}
+	}
+
+	fn range(&self, aabb: &Aabb<T, N>) -> Vec<Key> {
+		let mut result = Vec::new();
+		self.visit(aabb, |i| {
+			if aabb.contains(self.slab.point(i)) {
+				result.push(Key(i));
+			}
+		});
+		result
+	}
+}
+
+#[derive(Debug, Clone)]
+struct OrthNode<T, const N: usize>
+where T: Copy + Debug {
+	bounds: Aabb<T, N>,
+	items: Vec<usize>,
+	// either empty, or `2^N` children
+	children: Vec<OrthNode<T, N>>,
+	// the amount of points in this node and its children
+	count: usize,
+}
+impl<T, const N: usize> OrthNode<T, N>
+where T: Copy + Debug + num_traits::Float {
+	fn new(bounds: Aabb<T, N>) -> Self {
+		Self {
+			bounds,
+			items: Vec::new(),
+			children: Vec::new(),
+			count: 0,
+		}
+	}
+
+	// which child `point` belongs in: bit `i` is set if it's in the upper half of axis `i`
+	fn child(&self, point: Point<T, N>) -> usize {
+		let center = self.bounds.center();
+		(0..N).fold(0, |acc, i| acc | (((point[i] >= center[i]) as usize) << i))
+	}
+
+	fn split(&mut self) {
+		let center = self.bounds.center();
+		self.children = (0..1 << N)
+			.map(|c: usize| {
+				let corner = Point::new(std::array::from_fn(|i| {
+					if c & (1 << i) == 0 { self.bounds.min[i] } else { self.bounds.max[i] }
+				}));
+				OrthNode::new(Aabb::new(center, corner))
+			})
+			.collect();
+	}
+
+	fn collect(&self, out: &mut Vec<usize>) {
+		out.extend_from_slice(&self.items);
+		for child in &self.children {
+			child.collect(out);
+		}
+	}
+}
+
+/**
+a tree that splits space into `2^N` equal parts at every level. in 2d, this is a [`Quadtree`],
+and in 3d an [`Octree`].
+
+each node holds up to `capacity` points before splitting. the tree grows to fit points
+inserted outside its bounds, and inserting a point that isn't finite panics.
+*/
+#[derive(Debug, Clone)]
+pub struct Orthtree<T, V, const N: usize>
+where T: Copy + Debug {
+	root: OrthNode<T, N>,
+	capacity: usize,
+	// how many times the root has grown, which raises the depth limit
+	grown: usize,
+	slab: Slab<T, V, N>,
+}
+
+pub type Quadtree<T, V> = Orthtree<T, V, 2>;
+pub type Octree<T, V> = Orthtree<T, V, 3>;
+
+impl<T, V, const N: usize> Orthtree<T, V, N>
+where T: Copy + Debug + num_traits::Float {
+	/**
+	constructs an empty tree covering `bounds`, splitting nodes with more than 8 points.
+
+	panics if `bounds` has no size along an axis, or isn't finite.
+	*/
+	pub fn new(bounds: Aabb<T, N>) -> Self {
+		Self::with_capacity(bounds, 8)
+	}
+
+	/// constructs an empty tree covering `bounds`, splitting nodes with more than `capacity` points.
+	/// panics if `bounds` has no size along an axis, or isn't finite.
+	pub fn with_capacity(bounds: Aabb<T, N>, capacity: usize) -> Self {
+		// the root couldn't grow from an empty or infinite box
+		let size = bounds.size();
+		assert!((0..N).all(|i| size[i] > T::zero() && size[i].is_finite()), "bounds must have a finite, non-zero size");
+		Self {
+			root: OrthNode::new(bounds),
+			capacity: capacity.max(1),
+			grown: 0,
+			slab: Slab::new(),
+		}
+	}
+
+	/// returns the area covered by the tree.
+	pub fn bounds(&self) -> Aabb<T, N> {
+		self.root.bounds
+	}
+
+	// doubles the root towards `point`, until it's inside
+	fn grow(&mut self, point: Point<T, N>) {
+		while !self.root.bounds.contains(point) {
+			let old = &self.root.bounds;
+			let size = old.size();
+			let mut child = 0;
+			let min = Point::new(std::array::from_fn(|i| {
+				if point[i] < old.min[i] {
+					// grow downwards, so the old root is the upper half
+					child |= 1 << i;
+					old.min[i] - size[i]
+				} else {
+					old.min[i]
+				}
+			}));
+			let bounds = Aabb::new(min, min + size + size);
+
+			let mut root = OrthNode::new(bounds);
+			root.split();
+			root.count = self.root.count;
+			let old = std::mem::replace(&mut self.root, root);
+			self.root.children[child] = old;
+			self.grown += 1;
+		}
+	}
+
+	fn insert_at(node: &mut OrthNode<T, N>, slab: &Slab<T, V, N>, index: usize, capacity: usize, depth: usize) {
+		let point = slab.point(index);
+		node.count += 1;
+		if !node.children.is_empty() {
+			let c = node.child(point);
+			return Self::insert_at(&mut node.children[c], slab, index, capacity, depth - 1);
+		}
+		node.items.push(index);
+		// `depth` stops identical points from splitting forever
+		if node.items.len() > capacity && depth > 0 {
+			node.split();
+			for i in std::mem::take(&mut node.items) {
+				let c = node.child(slab.point(i));
+				Self::insert_at(&mut node.children[c], slab, i, capacity, depth - 1);
+			}
+		}
+	}
+
+	fn remove_at(node: &mut OrthNode<T, N>, point: Point<T, N>, index: usize, capacity: usize) -> bool {
+		if node.children.is_empty() {
+			let Some(i) = node.items.iter().position(|&x| x == index) else {
+				return false;
+			};
+			node.items.swap_remove(i);
+			node.count -= 1;
+			return true;
+		}
+		let c = node.child(point);
+		if !Self::remove_at(&mut node.children[c], point, index, capacity) {
+			return false;
+		}
+		node.count -= 1;
+		// merge children back once they'd fit in this node
+		if node.count <= capacity {
+			let mut items = Vec::new();
+			node.collect(&mut items);
+			node.children.clear();
+			node.items = items;
+		}
+		true
+	}
+}
+impl<T, V, const N: usize> Spatial<T, V, N> for Orthtree<T, V, N>
+where T: Copy + Debug + num_traits::Float {
+	fn insert(&mut self, point: Point<T, N>, value: V) -> Key {
+		assert!(point.unwrap().iter().all(|x| x.is_finite()), "points must be finite");
+		self.grow(point);
+		let index = self.slab.insert(point, value);
+		Self::insert_at(&mut self.root, &self.slab, index, self.capacity, self.grown + 32);
+		Key(index)
+	}
+
+	fn remove(&mut self, key: Key) -> Option<(Point<T, N>, V)> {
+		let point = self.slab.get(key.0)?.0;
+		Self::remove_at(&mut self.root, point, key.0, self.capacity);
+		self.slab.remove(key.0)
+	}
+
+	fn get(&self, key: Key) -> Option<(Point<T, N>, &V)> {
+		self.slab.get(key.0).map(|(p, v)| (*p, v))
+	}
+
+	fn len(&self) -> usize {
+		self.slab.len()
+	}
+
+	fn radius(&self, center: Point<T, N>, radius: T) -> Vec<Key> {
+		let sphere = Sphere::new(center, radius);
+		let mut result = Vec::new();
+		let mut stack = vec![&self.root];
+		while let Some(node) = stack.pop() {
+			if !sphere.overlaps_aabb(&node.bounds) {
+				continue;
+			}
+			for &i in &node.items {
+				if sphere.contains(self.slab.point(i)) {
+					result.push(Key(i));
+				}
+			}
+			stack.extend(&node.children);
+		}
+		result
+	}
+
+	fn nearest(&self, point: Point<T, N>, k: usize) -> Vec<Key> {
+		let mut nearest = Nearest::new(k);
+		// visit nodes closest first, so far away nodes can be skipped entirely
+		let mut queue = BinaryHeap::new();
+		let mut nodes = vec![&self.root];
+		queue.push(std::cmp::Reverse(Dist(self.root.bounds.distance_sq(point), 0)));
+
+		while let Some(std::cmp::Reverse(Dist(dist, n))) = queue.pop() {
+			if nearest.worst().is_some_and(|w| dist > w) {
+				break;
+			}
+			let node = nodes[n];
+			for &i in &node.items {
+				nearest.push(self.slab.point(i).distance_sq(point), i);
+			}
+			for child in &node.children {
+				nodes.push(child);
+				queue.push(std::cmp::Reverse(Dist(child.bounds.distance_sq(point), nodes.len() - 1)));
+			}
+		}
+
+		nearest.finish()
+	}
+
+	fn range(&self, aabb: &Aabb<T, N>) -> Vec<Key> {
+		let mut result = Vec::new();
+		let mut stack = vec![&self.root];
+		while let Some(node) = stack.pop() {
+			if !aabb.overlaps(&node.bounds) {
+				continue;
+			}
+			if aabb.contains_aabb(&node.bounds) {
+				let mut items = Vec::new();
+				node.collect(&mut items);
+				result.extend(items.into_iter().map(Key));
+				continue;
+			}
+			for &i in &node.items {
+				if aabb.contains(self.slab.point(i)) {
+					result.push(Key(i));
+				}
+			}
+			stack.extend(&node.children);
+		}
+		result
+	}
+}
+
+#[derive(Debug, Clone)]
+struct KdNode<T, const N: usize>
+where T: Copy + Debug {
+	// kept here, so removed nodes can still act as a split
+	point: Point<T, N>,
+	index: usize,
+	axis: usize,
+	left: Option<usize>,
+	right: Option<usize>,
+	removed: bool,
+}
+
+/**
+a [k-d tree](https://en.wikipedia.org/wiki/K-d_tree), which splits space at each point in turn.
+
+insertions don't rebalance the tree, so inserting points in a sorted order makes it degrade.
+[`Self::from_points()`] and [`Self::rebuild()`] construct a balanced tree. removed points are
+only marked as removed, and the tree is rebuilt once they outnumber the remaining points.
+inserting a point that isn't finite panics.
+*/
+#[derive(Debug, Clone)]
+pub struct KdTree<T, V, const N: usize>
+where T: Copy + Debug {
+	nodes: Vec<KdNode<T, N>>,
+	// the node each slab index lives in
+	lookup: Vec<usize>,
+	root: Option<usize>,
+	removed: usize,
+	slab: Slab<T, V, N>,
+}
+impl<T, V, const N: usize> KdTree<T, V, N>
+where T: Copy + Debug + num_traits::Float {
+	/// constructs an empty tree.
+	pub fn new() -> Self {
+		Self {
+			nodes: Vec::new(),
+			lookup: Vec::new(),
+			root: None,
+			removed: 0,
+			slab: Slab::new(),
+		}
+	}
+
+	/// constructs a balanced tree from a list of points, returning their keys in the same order.
+	pub fn from_points(points: impl IntoIterator<Item = (Point<T, N>, V)>) -> (Self, Vec<Key>) {
+		let mut tree = Self::new();
+		let keys = points.into_iter().map(|(p, v)| Key(tree.slab.insert(p, v))).collect();
+		tree.rebuild();
+		(tree, keys)
+	}
+
+	/// rebuilds the tree, balancing it and dropping removed points.
+	pub fn rebuild(&mut self) {
+		let mut indices = (0..self.slab.items.len())
+			.filter(|&i| self.slab.items[i].is_some())
+			.collect::<Vec<_>>();
+		self.nodes.clear();
+		self.lookup = vec![usize::MAX; self.slab.items.len()];
+		self.removed = 0;
+		self.root = self.build(&mut indices, 0);
+	}
+
+	fn build(&mut self, indices: &mut [usize], depth: usize) -> Option<usize> {
+		if indices.is_empty() {
+			return None;
+		}
+		let axis = depth % N;
+		let mid = indices.len() / 2;
+		let slab = &self.slab;
+		indices.select_nth_unstable_by(mid, |&a, &b| {
+			slab.point(a)[axis].partial_cmp(&slab.point(b)[axis]).unwrap_or(Ordering::Equal)
+		});
+		let index = indices[mid];
+		let (left, right) = indices.split_at_mut(mid);
+		let left = self.build(left, depth + 1);
+		let right = self.build(&mut right[1..], depth + 1);
+		self.nodes.push(KdNode {
+			point: self.slab.point(index),
+			index,
+			axis,
+			left,
+			right,
+			removed: false,
+		});
+		self.lookup[index] = self.nodes.len() - 1;
+		Some(self.nodes.len() - 1)
+	}
+
+	fn nearest_at(&self, point: Point<T, N>, nearest: &mut Nearest<T>) {
+		// (node, squared distance to the split that had to be crossed to reach it).
+		// iterative, as a degenerate tree can be as deep as it has points
+		let mut stack = Vec::from_iter(self.root.map(|x| (x, T::zero())));
+		while let Some((n, cross)) = stack.pop() {
+			// only cross the split if the closest possible point over there could be kept
+			if nearest.worst().is_some_and(|w| cross > w) {
+				continue;
+			}
+			let node = &self.nodes[n];
+			if !node.removed {
+				nearest.push(node.point.distance_sq(point), node.index);
+			}
+			let diff = point[node.axis] - node.point[node.axis];
+			let (near, far) = if diff < T::zero() { (node.left, node.right) } else { (node.right, node.left) };
+			// the near side is pushed last, so it's searched first
+			stack.extend(far.map(|x| (x, diff * diff)));
+			stack.extend(near.map(|x| (x, T::zero())));
+		}
+	}
+
+	// calls `f` with every live node inside `aabb`
+	fn visit(&self, aabb: &Aabb<T, N>, mut f: impl FnMut(usize)) {
+		let mut stack = Vec::from_iter(self.root);
+		while let Some(n) = stack.pop() {
+			let node = &self.nodes[n];
+			if !node.removed && aabb.contains(node.point) {
+				f(node.index);
+			}
+			let x = node.point[node.axis];
+			// points equal to the split can be on either side
+			if let Some(left) = node.left && aabb.min[node.axis] <= x {
+				stack.push(left);
+			}
+			if let Some(right) = node.right && aabb.max[node.axis] >= x {
+				stack.push(right);
+			}
+		}
+	}
+}
+impl<T, V, const N: usize> Default for KdTree<T, V, N>
+where T: Copy + Debug + num_traits::Float {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+impl<T, V, const N: usize> Spatial<T, V, N> for KdTree<T, V, N>
+where T: Copy + Debug + num_traits::Float {
+	fn insert(&mut self, point: Point<T, N>, value: V) -> Key {
+		assert!(point.unwrap().iter().all(|x| x.is_finite()), "points must be finite");
+		let index = self.slab.insert(point, value);
+		if self.lookup.len() <= index {
+			self.lookup.resize(index + 1, usize::MAX);
+		}
+
+		let mut parent = None;
+		let mut next = self.root;
+		let mut depth = 0;
+		while let Some(n) = next {
+			let node = &self.nodes[n];
+			let go_left = point[node.axis] < node.point[node.axis];
+			parent = Some((n, go_left));
+			next = if go_left { node.left } else { node.right };
+			depth += 1;
+		}
+
+		self.nodes.push(KdNode {
+			point,
+			index,
+			axis: depth % N,
+			left: None,
+			right: None,
+			removed: false,
+		});
+		let n = self.nodes.len() - 1;
+		self.lookup[index] = n;
+		match parent {
+			None => self.root = Some(n),
+			Some((p, true)) => self.nodes[p].left = Some(n),
+			Some((p, false)) => self.nodes[p].right = Some(n),
+		}
+		Key(index)
+	}
+
+	fn remove(&mut self, key: Key) -> Option<(Point<T, N>, V)> {
+		let item = self.slab.remove(key.0)?;
+		// the node stays behind as a split, until the tree is rebuilt
+		self.nodes[self.lookup[key.0]].removed = true;
+		self.removed += 1;
+		if self.removed > self.len() {
+			self.rebuild();
+		}
+		Some(item)
+	}
+
+	fn get(&self, key: Key) -> Option<(Point<T, N>, &V)> {
+		self.slab.get(key.0).map(|(p, v)| (*p, v))
+	}
+
+	fn len(&self) -> usize {
+		self.slab.len()
+	}
+
+	fn radius(&self, center: Point<T, N>, radius: T) -> Vec<Key> {
+		let sphere = Sphere::new(center, radius);
+		let mut result = Vec::new();
+		self.visit(&sphere.aabb(), |i| {
+			if sphere.contains(self.slab.point(i)) {
+				result.push(Key(i));
+			}
+		});
+		result
+	}
+
+	fn nearest(&self, point: Point<T, N>, k: usize) -> Vec<Key> {
+		let mut nearest = Nearest::new(k);
+		self.nearest_at(point, &mut nearest);
+		nearest.finish()
+	}
+
+	fn range(&self, aabb: &Aabb<T, N>) -> Vec<Key> {
+		let mut result = Vec::new();
+		self.visit(aabb, |i| result.push(Key(i)));
+		result
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::point;
+	use super::*;
+
+	// checks every query against a brute force search, while inserting and removing points
+	#[cfg(feature = "rng")]
+	fn check(mut index: impl Spatial<f64, usize, 2>) {
+		use crate::rng::XorShift64;
+
+		let mut rng = XorShift64::new(7);
+		let mut points = Vec::new();
+
+		for i in 0..600 {
+			let p = point![rng.range(-50.0, 50.0), rng.range(-50.0, 50.0)];
+			points.push(Some((index.insert(p, i), p)));
+
+			// remove every third point again
+			if i % 3 == 0 {
+				let j = (rng.nextu() % points.len() as u64) as usize;
+				if let Some((key, p)) = points[j].take() {
+					assert_eq!(index.remove(key).map(|x| x.0.unwrap()), Some(p.unwrap()));
+					assert!(index.get(key).is_none());
+				}
+			}
+		}
+
+		let live = points.iter().flatten().copied().collect::<Vec<_>>();
+		assert_eq!(index.len(), live.len());
+
+		for _ in 0..50 {
+			let q = point![rng.range(-60.0, 60.0), rng.range(-60.0, 60.0)];
+
+			let mut found = index.radius(q, 12.0);
+			let mut expected = live.iter().filter(|x| x.1.distance(q) <= 12.0).map(|x| x.0).collect::<Vec<_>>();
+			found.sort();
+			expected.sort();
+			assert_eq!(found, expected);
+
+			let aabb = Aabb::new(q, q + point![15.0, 8.0]);
+			let mut found = index.range(&aabb);
+			let mut expected = live.iter().filter(|x| aabb.contains(x.1)).map(|x| x.0).collect::<Vec<_>>();
+			found.sort();
+			expected.sort();
+			assert_eq!(found, expected);
+
+			let found = index.nearest(q, 5);
+			let mut expected = live.clone();
+			expected.sort_by(|a, b| a.1.distance_sq(q).partial_cmp(&b.1.distance_sq(q)).unwrap());
+			let expected = expected.iter().take(5).map(|x| x.0).collect::<Vec<_>>();
+			assert_eq!(found, expected);
+		}
+	}
+
+	#[cfg(feature = "rng")]
+	#[test]
+	fn test_grid() {
+		check(HashGrid::new(10.0));
+	}
+
+	#[cfg(feature = "rng")]
+	#[test]
+	fn test_quadtree() {
+		// starts out too small, so it has to grow
+		check(Quadtree::with_capacity(Aabb::new(point![0.0, 0.0], point![10.0, 10.0]), 4));
+	}
+
+	#[cfg(feature = "rng")]
+	#[test]
+	fn test_kdtree() {
+		check(KdTree::new());
+	}
+
+	#[test]
+	fn test_grid_far() {
+		// a point thousands of cells away, which shouldn't mean stepping through every cell between
+		let mut grid = HashGrid::new(1.0);
+		let a = grid.insert(point![0.0, 0.0], ());
+		let b = grid.insert(point![3000.0, -3000.0], ());
+
+		assert_eq!(grid.nearest(point![0.5, 0.5], 2), vec![a, b]);
+		assert_eq!(grid.nearest(point![1e9, 0.0], 1), vec![b]);
+
+		let mut found = grid.radius(point![0.0, 0.0], f64::INFINITY);
+		found.sort();
+		assert_eq!(found, vec![a, b]);
+		let mut found = grid.range(&Aabb::new(point![f64::NEG_INFINITY, -1e30], point![1e30, 1.0]));
+		found.sort();
+		assert_eq!(found, vec![a, b]);
+	}
+
+	#[test]
+	fn test_kdtree_duplicates() {
+		// points sharing the split coordinate end up on both sides of it
+		let (tree, keys) = KdTree::from_points([(point![1.0, 0.0], ()), (point![1.0, 1.0], ()), (point![1.0, 2.0], ())]);
+
+		let mut found = tree.range(&Aabb::new(point![1.0, -1.0], point![2.0, 3.0]));
+		found.sort();
+		assert_eq!(found, keys);
+		let mut found = tree.radius(point![0.0, 1.0], 1.5);
+		found.sort();
+		assert_eq!(found, keys);
+		assert_eq!(tree.nearest(point![0.0, 0.0], 1), vec![keys[0]]);
+		assert_eq!(tree.nearest(point![1.0, 2.0], 3), vec![keys[2], keys[1], keys[0]]);
+	}
+
+	#[test]
+	fn test_kdtree_sorted() {
+		// inserting in order makes a tree as deep as it has points, searched here with a small stack
+		let mut tree = KdTree::new();
+		let keys = (0..5000).map(|i| tree.insert(point![i as f64, i as f64], i)).collect::<Vec<_>>();
+		std::thread::Builder::new()
+			.stack_size(32 * 1024)
+			.spawn(move || {
+				assert_eq!(tree.nearest(point![-1.0, -1.0], 2), vec![keys[0], keys[1]]);
+				assert_eq!(tree.nearest(point![4000.2, 4000.2], 1), vec![keys[4000]]);
+			})
+			.unwrap()
+			.join()
+			.unwrap();
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_kdtree_infinite() {
+		KdTree::new().insert(point![0.0, f64::INFINITY], ());
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_orthtree_empty_bounds() {
+		Quadtree::<f64, ()>::new(Aabb::new(point![1.0, 1.0], point![1.0, 1.0]));
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_orthtree_nan() {
+		let mut tree = Quadtree::new(Aabb::new(point![0.0, 0.0], point![1.0, 1.0]));
+		tree.insert(point![f64::NAN, 0.0], ());
+	}
+
+	#[test]
+	fn test_octree() {
+		let mut tree = Octree::new(Aabb::new(point![0.0, 0.0, 0.0], point![4.0, 4.0, 4.0]));
+		let keys = (0..64)
+			.map(|i| tree.insert(point![(i % 4) as f64, ((i / 4) % 4) as f64, (i / 16) as f64], i))
+			.collect::<Vec<_>>();
+
+		assert_eq!(tree.nearest(point![0.1, 0.0, 0.0], 1), vec![keys[0]]);
+		assert_eq!(tree.radius(point![1.0, 1.0, 1.0], 1.0).len(), 7);
+		assert_eq!(tree.range(&Aabb::new(point![0.5, 0.5, 0.5], point![2.5, 2.5, 2.5])).len(), 8);
+
+		for key in keys {
+			assert!(tree.remove(key).is_some());
+		}
+		assert!(tree.is_empty());
+		assert!(tree.nearest(point![0.0, 0.0, 0.0], 3).is_empty());
+	}
+}